@@ -2,6 +2,7 @@
 //! Defines the structure for parsing command-line arguments
 //! using the `clap` crate.
 
+use crate::{models::condition::ShaderDefValue, parser::preprocessor::parse_shader_def};
 use clap::Parser;
 use std::{env, path::PathBuf};
 
@@ -45,7 +46,7 @@ pub struct Args {
     pub ast_only: bool,
 
     /// Input directory to process.
-    /// If not specified, the program will look for .wgsl files in the current directory.
+    /// If not specified, the program will look for .wgsl and .wesl files in the current directory and its subdirectories.
     #[arg(short = 'I', long = "input")]
     pub directory: Option<PathBuf>,
 
@@ -53,6 +54,20 @@ pub struct Args {
     #[arg(short = 'W', long)]
     pub show_undocumented: bool,
 
+    /// Check modules used together by entry points for conflicting `@group`/`@binding` slots
+    /// instead of generating documentation. Exits with a non-zero code if conflicts are found.
+    #[arg(short = 'B', long)]
+    pub check_bindings: bool,
+
+    /// Shader def of the shader configuration to document, as `NAME` or `NAME=VALUE`. Can be repeated.
+    /// If specified, only the code enabled for that configuration is documented.
+    #[arg(short = 'S', long = "shader-def", value_name = "NAME=VALUE", value_parser = parse_shader_def)]
+    pub shader_defs: Vec<(String, ShaderDefValue)>,
+
+    /// Skip top-level items which can't be parsed, reporting them as warnings, instead of failing.
+    #[arg(short = 'R', long)]
+    pub recover: bool,
+
     /// Show credits
     #[arg(short = 'C', long)]
     pub credits: bool,
@@ -3,11 +3,14 @@
 //! documentation from parsed WGSL shader modules.
 
 use crate::{
-    models::{function::Function, import::Import, structure::Structure, ComponentInfo, Wgsl},
-    utils::html::to_html,
+    models::{
+        alias::Alias, condition::Condition, function::Function, import::Import,
+        layout::BindGroupLayout, structure::Structure, ComponentInfo, Wgsl,
+    },
+    utils::{html::to_html, path::module_dir},
 };
-use std::path::Path;
-use tera::Tera;
+use std::{collections::HashMap, path::Path};
+use tera::{Tera, Value};
 
 #[cfg(doc)]
 /// Assets module containing static files as constants.
@@ -41,22 +44,34 @@ pub mod assets {
 
 /// Trait for documentation generators.
 pub trait Generator {
-    /// Generates documentation for a function.
+    /// Generates documentation for a function, available under any of the alternative preprocessor `conditions`.
     fn generate_fn(
         &mut self,
         pkg_name: &str,
         assets_subpath: impl AsRef<Path>,
         function: &Function,
         imports: &[Import],
+        conditions: &[Vec<Condition>],
     ) -> String;
 
-    /// Generates documentation for a structure.
+    /// Generates documentation for a structure, available under any of the alternative preprocessor `conditions`.
     fn generate_struct(
         &mut self,
         pkg_name: &str,
         assets_subpath: impl AsRef<Path>,
         structure: &Structure,
         imports: &[Import],
+        conditions: &[Vec<Condition>],
+    ) -> String;
+
+    /// Generates documentation for a type alias, available under any of the alternative preprocessor `conditions`.
+    fn generate_alias(
+        &mut self,
+        pkg_name: &str,
+        assets_subpath: impl AsRef<Path>,
+        alias: &Alias,
+        imports: &[Import],
+        conditions: &[Vec<Condition>],
     ) -> String;
 
     /// Generates the index page documentation.
@@ -75,6 +90,24 @@ pub trait Generator {
         modules: &[ComponentInfo],
     ) -> String;
 
+    /// Generates the entry points overview documentation,
+    /// listing every entry point function across all modules.
+    fn generate_entry_points(
+        &mut self,
+        pkg_name: &str,
+        assets_subpath: impl AsRef<Path>,
+        shaders: &[Wgsl],
+    ) -> String;
+
+    /// Generates the bind group layout documentation,
+    /// merging the bindings of all modules grouped by `@group`.
+    fn generate_bind_groups(
+        &mut self,
+        pkg_name: &str,
+        assets_subpath: impl AsRef<Path>,
+        shaders: &[Wgsl],
+    ) -> String;
+
     /// Generates documentation for a module.
     fn generate_module(
         &mut self,
@@ -108,6 +141,10 @@ impl TeraGenerator {
     pub const INDEX_TEMPLATE: &str = include_str!("templates/index.html.tera");
     /// Modules HTML template source.
     pub const MODULES_TEMPLATE: &str = include_str!("templates/modules.html.tera");
+    /// Entry points HTML template source.
+    pub const ENTRY_POINTS_TEMPLATE: &str = include_str!("templates/entry_points.html.tera");
+    /// Bind groups HTML template source.
+    pub const BIND_GROUPS_TEMPLATE: &str = include_str!("templates/bind_groups.html.tera");
     /// Module HTML template source.
     pub const MODULE_TEMPLATE: &str = include_str!("templates/module.html.tera");
     /// Source HTML template source.
@@ -116,6 +153,8 @@ impl TeraGenerator {
     pub const FN_TEMPLATE: &str = include_str!("templates/fn.html.tera");
     /// Structure HTML template source.
     pub const STRUCT_TEMPLATE: &str = include_str!("templates/struct.html.tera");
+    /// Alias HTML template source.
+    pub const ALIAS_TEMPLATE: &str = include_str!("templates/alias.html.tera");
 }
 
 #[cfg(doc)]
@@ -128,6 +167,10 @@ impl TeraGenerator {
     pub const INDEX_TEMPLATE: &str = "...";
     /// Modules HTML template source.
     pub const MODULES_TEMPLATE: &str = "...";
+    /// Entry points HTML template source.
+    pub const ENTRY_POINTS_TEMPLATE: &str = "...";
+    /// Bind groups HTML template source.
+    pub const BIND_GROUPS_TEMPLATE: &str = "...";
     /// Module HTML template source.
     pub const MODULE_TEMPLATE: &str = "...";
     /// Source HTML template source.
@@ -136,30 +179,43 @@ impl TeraGenerator {
     pub const FN_TEMPLATE: &str = "...";
     /// Structure HTML template source.
     pub const STRUCT_TEMPLATE: &str = "...";
+    /// Alias HTML template source.
+    pub const ALIAS_TEMPLATE: &str = "...";
 }
 
 impl TeraGenerator {
     /// Array of all template names and their sources.
-    pub const TEMPLATES: [(&str, &str); 8] = [
+    pub const TEMPLATES: [(&str, &str); 11] = [
         ("macros.tera", Self::MACROS),
         ("base.html.tera", Self::BASE_TEMPLATE),
         ("index.html.tera", Self::INDEX_TEMPLATE),
         ("modules.html.tera", Self::MODULES_TEMPLATE),
+        ("entry_points.html.tera", Self::ENTRY_POINTS_TEMPLATE),
+        ("bind_groups.html.tera", Self::BIND_GROUPS_TEMPLATE),
         ("module.html.tera", Self::MODULE_TEMPLATE),
         ("source.html.tera", Self::SOURCE_TEMPLATE),
         ("fn.html.tera", Self::FN_TEMPLATE),
         ("struct.html.tera", Self::STRUCT_TEMPLATE),
+        ("alias.html.tera", Self::ALIAS_TEMPLATE),
     ];
 
     /// Creates a new TeraGenerator with an optional base URL.
     pub fn new(base_url: Option<String>) -> Self {
         let mut tera = Tera::default();
         tera.add_raw_templates(Self::TEMPLATES).unwrap();
+        tera.register_filter("module_dir", module_dir_filter);
 
         TeraGenerator { tera, base_url }
     }
 }
 
+/// Tera filter converting a module name to its relative directory, see [`module_dir`].
+fn module_dir_filter(value: &Value, _: &HashMap<String, Value>) -> tera::Result<Value> {
+    let module_name = tera::from_value::<String>(value.clone())?;
+
+    Ok(Value::String(module_dir(&module_name)))
+}
+
 impl Generator for TeraGenerator {
     fn generate_fn(
         &mut self,
@@ -167,6 +223,7 @@ impl Generator for TeraGenerator {
         assets_subpath: impl AsRef<Path>,
         function: &Function,
         imports: &[Import],
+        conditions: &[Vec<Condition>],
     ) -> String {
         let mut ctx = tera::Context::new();
         ctx.insert("pkg_name", pkg_name);
@@ -184,14 +241,17 @@ impl Generator for TeraGenerator {
             );
         }
 
+        ctx.insert("conditions", &rendered_conditions(conditions));
         ctx.insert("function_info", &function.info_rich_text());
+        ctx.insert("stage", &function.stage());
+        ctx.insert("workgroup_size", function.workgroup_size());
+        ctx.insert("modifier", &function.modifier());
         ctx.insert("args", &function.rendered_args(imports));
         ctx.insert(
             "return_type",
-            &function
-                .return_type()
-                .map(|ty| ty.rendered_type(imports, false)),
+            &function.return_type().map(|ty| ty.rendered_type(imports)),
         );
+        ctx.insert("return_attributes", &function.rendered_return_attributes());
 
         self.tera.render("fn.html.tera", &ctx).unwrap()
     }
@@ -202,6 +262,7 @@ impl Generator for TeraGenerator {
         assets_subpath: impl AsRef<Path>,
         structure: &Structure,
         imports: &[Import],
+        conditions: &[Vec<Condition>],
     ) -> String {
         let mut ctx = tera::Context::new();
         ctx.insert("pkg_name", pkg_name);
@@ -219,12 +280,44 @@ impl Generator for TeraGenerator {
             );
         }
 
+        ctx.insert("conditions", &rendered_conditions(conditions));
         ctx.insert("structure_info", &structure.info_rich_text());
         ctx.insert("fields", &structure.rendered_fields(imports));
 
         self.tera.render("struct.html.tera", &ctx).unwrap()
     }
 
+    fn generate_alias(
+        &mut self,
+        pkg_name: &str,
+        assets_subpath: impl AsRef<Path>,
+        alias: &Alias,
+        imports: &[Import],
+        conditions: &[Vec<Condition>],
+    ) -> String {
+        let mut ctx = tera::Context::new();
+        ctx.insert("pkg_name", pkg_name);
+
+        if let Some(base_url) = &self.base_url {
+            ctx.insert("assets_subpath", base_url.trim_end_matches('/'));
+        } else {
+            ctx.insert(
+                "assets_subpath",
+                assets_subpath
+                    .as_ref()
+                    .to_str()
+                    .unwrap_or("")
+                    .trim_end_matches('/'),
+            );
+        }
+
+        ctx.insert("conditions", &rendered_conditions(conditions));
+        ctx.insert("alias_info", &alias.info_rich_text());
+        ctx.insert("aliased_type", &alias.rendered_type(imports));
+
+        self.tera.render("alias.html.tera", &ctx).unwrap()
+    }
+
     fn generate_index(
         &mut self,
         pkg_name: &str,
@@ -281,6 +374,70 @@ impl Generator for TeraGenerator {
         self.tera.render("modules.html.tera", &ctx).unwrap()
     }
 
+    fn generate_entry_points(
+        &mut self,
+        pkg_name: &str,
+        assets_subpath: impl AsRef<Path>,
+        shaders: &[Wgsl],
+    ) -> String {
+        let mut ctx = tera::Context::new();
+        ctx.insert("pkg_name", pkg_name);
+
+        if let Some(base_url) = &self.base_url {
+            ctx.insert("assets_subpath", base_url.trim_end_matches('/'));
+        } else {
+            ctx.insert(
+                "assets_subpath",
+                assets_subpath
+                    .as_ref()
+                    .to_str()
+                    .unwrap_or("")
+                    .trim_end_matches('/'),
+            );
+        }
+
+        let entry_points = shaders
+            .iter()
+            .flat_map(|shader| {
+                shader
+                    .functions
+                    .iter()
+                    .filter(|f| f.is_entry_point())
+                    .map(|f| f.rendered_entry_point(&shader.module_name, &shader.imports))
+            })
+            .collect::<Vec<_>>();
+        ctx.insert("entry_points", &entry_points);
+
+        self.tera.render("entry_points.html.tera", &ctx).unwrap()
+    }
+
+    fn generate_bind_groups(
+        &mut self,
+        pkg_name: &str,
+        assets_subpath: impl AsRef<Path>,
+        shaders: &[Wgsl],
+    ) -> String {
+        let mut ctx = tera::Context::new();
+        ctx.insert("pkg_name", pkg_name);
+
+        if let Some(base_url) = &self.base_url {
+            ctx.insert("assets_subpath", base_url.trim_end_matches('/'));
+        } else {
+            ctx.insert(
+                "assets_subpath",
+                assets_subpath
+                    .as_ref()
+                    .to_str()
+                    .unwrap_or("")
+                    .trim_end_matches('/'),
+            );
+        }
+
+        ctx.insert("bind_groups", &BindGroupLayout::new(shaders).rendered());
+
+        self.tera.render("bind_groups.html.tera", &ctx).unwrap()
+    }
+
     fn generate_module(
         &mut self,
         pkg_name: &str,
@@ -306,6 +463,18 @@ impl Generator for TeraGenerator {
         ctx.insert("source", &shader.module_name);
         ctx.insert("module", &shader.info_rich_text());
         ctx.insert("imports", &shader.imports);
+        ctx.insert("extensions", &shader.extensions());
+        ctx.insert("language_features", &shader.language_features());
+        ctx.insert("diagnostics", &shader.diagnostics());
+
+        let conditions = shader
+            .conditions
+            .iter()
+            .map(|((kind, name), conditions)| {
+                (format!("{kind}.{name}"), rendered_conditions(conditions))
+            })
+            .collect::<HashMap<_, _>>();
+        ctx.insert("conditions", &conditions);
 
         let bindings = shader
             .bindings
@@ -314,6 +483,13 @@ impl Generator for TeraGenerator {
             .collect::<Vec<_>>();
         ctx.insert("bindings", &bindings);
 
+        let global_vars = shader
+            .global_vars
+            .iter()
+            .map(|v| v.rendered(&shader.imports))
+            .collect::<Vec<_>>();
+        ctx.insert("global_vars", &global_vars);
+
         let constants = shader
             .constants
             .iter()
@@ -321,10 +497,17 @@ impl Generator for TeraGenerator {
             .collect::<Vec<_>>();
         ctx.insert("constants", &constants);
 
+        let overrides = shader
+            .overrides
+            .iter()
+            .map(|o| o.rendered(&shader.imports))
+            .collect::<Vec<_>>();
+        ctx.insert("overrides", &overrides);
+
         let functions = shader
             .functions
             .iter()
-            .map(|f| f.info_plain_text())
+            .map(|f| f.rendered_info())
             .collect::<Vec<_>>();
         ctx.insert("functions", &functions);
 
@@ -335,6 +518,13 @@ impl Generator for TeraGenerator {
             .collect::<Vec<_>>();
         ctx.insert("structures", &structures);
 
+        let aliases = shader
+            .aliases
+            .iter()
+            .map(|a| a.info_plain_text())
+            .collect::<Vec<_>>();
+        ctx.insert("aliases", &aliases);
+
         self.tera.render("module.html.tera", &ctx).unwrap()
     }
 
@@ -366,3 +556,21 @@ impl Generator for TeraGenerator {
         self.tera.render("source.html.tera", &ctx).unwrap()
     }
 }
+
+/// Renders alternative sets of preprocessor conditions as HTML-escaped strings.
+fn rendered_conditions(conditions: &[Vec<Condition>]) -> Vec<Vec<String>> {
+    conditions
+        .iter()
+        .map(|alternative| {
+            alternative
+                .iter()
+                .map(|condition| {
+                    condition
+                        .to_string()
+                        .replace('<', "&lt;")
+                        .replace('>', "&gt;")
+                })
+                .collect()
+        })
+        .collect()
+}
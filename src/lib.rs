@@ -10,7 +10,7 @@
 //! 
 //! ```
 //! **wgsldoc** is a documentation generator for WGSL (WebGPU Shading Language) shader modules. 
-//! It parses `.wgsl` files, extracts documentation comments, and generates a structured, 
+//! It parses `.wgsl` (and WESL `.wesl`) files, extracts documentation comments, and generates a structured, 
 //! searchable HTML site for easy browsing and reference.
 //! 
 //! This documentation contains all the necessary information to get started with `wgsldoc`,
@@ -51,8 +51,11 @@
 //!   -D, --target-dir <TARGET_DIR>  Target directory for the generated documentation 
 //!   -U, --base-url <BASE_URL>      Base URL for future website. If specified, it will be used to generate links in the documentation. Otherwise, the links will use `target_dir` as the base URL
 //!   -A, --ast-only                 Generate AST and print it to stdout instead of generating full documentation
-//!   -I, --input <FILES>            Input files to process. If not specified, the program will look for .wgsl files in the current directory
+//!   -I, --input <FILES>            Input files to process. If not specified, the program will look for .wgsl and .wesl files in the current directory and its subdirectories
 //!   -W, --show-undocumented        Show undocumented items in the documentation
+//!   -B, --check-bindings           Check modules used together by entry points for conflicting `@group`/`@binding` slots instead of generating documentation. Exits with a non-zero code if conflicts are found
+//!   -S, --shader-def <NAME=VALUE>  Shader def of the shader configuration to document, as `NAME` or `NAME=VALUE`. Can be repeated. If specified, only the code enabled for that configuration is documented
+//!   -R, --recover                  Skip top-level items which can't be parsed, reporting them as warnings, instead of failing
 //!   -C, --credits                  Show credits
 //!   -h, --help                     Print help (see more with '--help')
 //!   -V, --version                  Print version
@@ -61,13 +64,23 @@
 use crate::generator::assets;
 use fs_err as fs;
 use generator::Generator;
-use models::{import::RegisterImports, Wgsl};
-use parser::WgslParser;
+use models::{
+    condition::ShaderDefs,
+    function::FunctionModifier,
+    import::{ItemKind, RegisterImports},
+    layout::{binding_conflicts, BindingConflict},
+    types::TypeKind,
+    Wgsl,
+};
+use parser::{preprocessor::Preprocessor, WgslParser};
 use std::{
     collections::HashSet,
     path::{Path, PathBuf},
 };
-use utils::error::Error;
+use utils::{
+    error::Error,
+    path::{module_dir, module_name},
+};
 
 pub mod cli;
 pub mod generator;
@@ -78,12 +91,24 @@ pub mod utils;
 /// Type alias for raw icon data (favicon).
 pub type IconData = Vec<u8>;
 
+/// Options for loading the shaders of a [`Document`].
+#[derive(Debug, Clone, Default)]
+pub struct DocumentOptions {
+    /// Shader defs of the shader configuration to document. If given, only the code
+    /// enabled for that configuration is documented, and the disabled code is stripped.
+    pub shader_defs: Option<ShaderDefs>,
+    /// Whether to parse shaders in recovery mode, skipping top-level items which can't be parsed
+    /// (and reporting them as warnings) instead of failing.
+    pub recover: bool,
+}
+
 /// Represents a WGSL document used for generating documentation static website.
-/// It contains package name, favicon, README content, file registry, and parsed shaders.
+/// It contains package name, the options it was loaded with, favicon, README content, file registry, and parsed shaders.
 /// Must be "registered" using [`Document::register`] method before generating documentation,
 /// creating a [`RegisteredDocument`] invariant.
 pub struct Document {
     pkg_name: String,
+    options: DocumentOptions,
     favicon: IconData,
     readme: Option<String>,
     file_registry: HashSet<PathBuf>,
@@ -91,27 +116,76 @@ pub struct Document {
 }
 
 impl Document {
-    /// Creates a new `Document` by loading WGSL files and associated assets from the provided paths.
+    /// Creates a new `Document` by loading WGSL (`.wgsl` and `.wesl`) files and associated assets from the provided paths.
+    /// The code of every conditional compilation branch is documented.
     pub fn new(pkg_name: impl Into<String>, paths: &[impl AsRef<Path>]) -> Result<Document, Error> {
+        Document::with_options(pkg_name, paths, DocumentOptions::default())
+    }
+
+    /// Creates a new `Document` like [`Document::new`], loading the shaders with the given `options`.
+    pub fn with_options(
+        pkg_name: impl Into<String>,
+        paths: &[impl AsRef<Path>],
+        options: DocumentOptions,
+    ) -> Result<Document, Error> {
+        Document::load(pkg_name, paths, None, options)
+    }
+
+    /// Loads the shaders and assets from `paths`. Shaders under the package `root` are named
+    /// after their path relative to it (e.g. `lighting::pbr` for `lighting/pbr.wesl`),
+    /// other shaders after their file name.
+    fn load(
+        pkg_name: impl Into<String>,
+        paths: &[impl AsRef<Path>],
+        root: Option<&Path>,
+        options: DocumentOptions,
+    ) -> Result<Document, Error> {
         log::info!("Loading shaders...");
 
+        let preprocessor = match &options.shader_defs {
+            Some(shader_defs) => Preprocessor::with_shader_defs(shader_defs.clone()),
+            None => Preprocessor::new(),
+        };
+
         let mut readme = None;
         let mut favicon = None;
         let mut file_registry = HashSet::new();
         let mut shaders = vec![];
 
         for path in paths {
-            if path.as_ref().extension().is_some_and(|ext| ext == "wgsl") {
+            if is_shader_file(path.as_ref()) {
                 file_registry.insert(path.as_ref().to_owned());
 
-                if let Some(module_name) = path.as_ref().file_stem().and_then(|name| name.to_str())
-                {
-                    if module_name.starts_with('.') {
+                if let Some(file_stem) = path.as_ref().file_stem().and_then(|name| name.to_str()) {
+                    if file_stem.starts_with('.') {
                         continue;
                     }
 
+                    let relative_path = root.and_then(|root| path.as_ref().strip_prefix(root).ok());
+                    let module_name =
+                        &relative_path.map_or_else(|| file_stem.to_owned(), module_name);
                     let shader = fs::read_to_string(path)?;
-                    shaders.push(WgslParser::parse(module_name, &shader)?);
+
+                    if options.recover {
+                        let wgsl =
+                            WgslParser::parse_recovering(module_name, &shader, &preprocessor)?;
+
+                        for skipped_item in &wgsl.skipped_items {
+                            log::warn!(
+                                "Skipped item of module `{}` which couldn't be parsed, {}",
+                                wgsl.module_name,
+                                skipped_item
+                            );
+                        }
+
+                        shaders.push(wgsl);
+                    } else {
+                        shaders.push(WgslParser::parse_with_preprocessor(
+                            module_name,
+                            &shader,
+                            &preprocessor,
+                        )?);
+                    }
                 }
             } else {
                 match path.as_ref().file_name().and_then(|name| name.to_str()) {
@@ -124,6 +198,7 @@ impl Document {
 
         Ok(Document {
             pkg_name: pkg_name.into(),
+            options,
             favicon: favicon.unwrap_or(assets::DEFAULT_FAVICON.to_vec()),
             readme,
             file_registry,
@@ -132,31 +207,70 @@ impl Document {
     }
 
     /// Creates a `Document` by reading WGSL files and associated assets from the specified directory.
+    /// Shaders in subdirectories are nested modules of the package, named after their path
+    /// relative to the directory (e.g. `lighting::pbr` for `lighting/pbr.wesl`).
     pub fn open(
         pkg_name: impl Into<String>,
         directory: impl AsRef<Path>,
     ) -> Result<Document, Error> {
-        let paths = fs::read_dir(directory.as_ref())?
-            .filter_map(|e| e.ok())
-            .map(|e| e.path())
-            .collect::<Vec<_>>();
+        Document::open_with_options(pkg_name, directory, DocumentOptions::default())
+    }
+
+    /// Creates a `Document` like [`Document::open`], loading the shaders with the given `options`.
+    pub fn open_with_options(
+        pkg_name: impl Into<String>,
+        directory: impl AsRef<Path>,
+        options: DocumentOptions,
+    ) -> Result<Document, Error> {
+        let mut paths = vec![];
+        collect_package_files(directory.as_ref(), false, &mut paths)?;
 
-        Document::new(pkg_name, &paths)
+        Document::load(pkg_name, &paths, Some(directory.as_ref()), options)
     }
 
     /// Registers the document, resolving imports and preparing it for documentation generation.
     pub fn register(mut self) -> RegisteredDocument {
         log::info!("Registering document...");
 
+        // Registry of module names (or import paths declared with `#define_import_path`)
+        // and their items, used to resolve imports by module path
+        let modules = self
+            .shaders
+            .iter()
+            .map(|shader| shader.module_items())
+            .collect::<Vec<_>>();
+
+        // Shaders of the same module name (e.g. `foo.wgsl` and `foo.wesl`) can't be told apart
+        let mut module_names = HashSet::new();
+        for shader in &self.shaders {
+            if !module_names.insert(shader.module_name.as_str()) {
+                log::warn!(
+                    "Module `{}` is declared by several shaders! Its imports refer to the first one \
+                     and their documentation overwrites each other.",
+                    shader.module_name
+                );
+            }
+        }
+
         for shader in &mut self.shaders {
             for import in &mut shader.imports {
-                import.register(&self.file_registry);
+                if import.module_path().is_some() {
+                    import.register_module_path(&modules, &shader.module_name);
+                } else {
+                    import.register(&self.file_registry, &modules);
+                }
             }
 
             let same_module_types = shader
                 .structures
                 .iter()
-                .map(|s| s.name().to_owned())
+                .map(|s| (s.name().to_owned(), TypeKind::Struct))
+                .chain(
+                    shader
+                        .aliases
+                        .iter()
+                        .map(|a| (a.name().to_owned(), TypeKind::Alias)),
+                )
                 .collect::<Vec<_>>();
 
             for structure in &mut shader.structures {
@@ -164,6 +278,11 @@ impl Document {
                 structure.register_same_module_types(&same_module_types);
             }
 
+            for alias in &mut shader.aliases {
+                alias.register_imports(&shader.imports);
+                alias.register_same_module_types(&same_module_types);
+            }
+
             for function in &mut shader.functions {
                 function.register_imports(&shader.imports);
                 function.register_same_module_types(&same_module_types);
@@ -174,12 +293,24 @@ impl Document {
                 binding.register_same_module_types(&same_module_types);
             }
 
+            for global_var in &mut shader.global_vars {
+                global_var.register_imports(&shader.imports);
+                global_var.register_same_module_types(&same_module_types);
+            }
+
             for constant in &mut shader.constants {
                 constant.register_imports(&shader.imports);
                 constant.register_same_module_types(&same_module_types);
             }
+
+            for override_decl in &mut shader.overrides {
+                override_decl.register_imports(&shader.imports);
+                override_decl.register_same_module_types(&same_module_types);
+            }
         }
 
+        register_overrides(&mut self.shaders);
+
         RegisteredDocument {
             pkg_name: self.pkg_name,
             favicon: self.favicon,
@@ -199,6 +330,11 @@ impl Document {
         self.favicon.as_ref()
     }
 
+    /// Get field `options` from instance of `Document`.
+    pub fn options(&self) -> &DocumentOptions {
+        &self.options
+    }
+
     /// Get field `shaders` from instance of `RegisteredDocument`.
     pub fn shaders(&self) -> &[Wgsl] {
         &self.shaders
@@ -288,9 +424,21 @@ impl RegisteredDocument {
             generator.generate_modules_index(self.pkg_name(), path.as_ref(), &modules);
         fs::write(modules_index_path, modules_index_content)?;
 
+        // @/entry_points.html
+        let entry_points_path = concat_path(&path, "entry_points.html");
+        let entry_points_content =
+            generator.generate_entry_points(self.pkg_name(), path.as_ref(), &self.shaders);
+        fs::write(entry_points_path, entry_points_content)?;
+
+        // @/bind_groups.html
+        let bind_groups_path = concat_path(&path, "bind_groups.html");
+        let bind_groups_content =
+            generator.generate_bind_groups(self.pkg_name(), path.as_ref(), &self.shaders);
+        fs::write(bind_groups_path, bind_groups_content)?;
+
         // @/modules/<module_name>/index.html
         for shader in &self.shaders {
-            let module_path = concat_path(&modules_path, &shader.module_name);
+            let module_path = concat_path(&modules_path, &module_dir(&shader.module_name));
             fs::create_dir_all(&module_path)?;
 
             let module_index_path = concat_path(&module_path, "index.html");
@@ -307,6 +455,7 @@ impl RegisteredDocument {
                     path.as_ref(),
                     function,
                     &shader.imports,
+                    shader.conditions_of(ItemKind::Function, function.name()),
                 );
 
                 fs::write(function_path, function_content)?;
@@ -321,10 +470,25 @@ impl RegisteredDocument {
                     path.as_ref(),
                     structure,
                     &shader.imports,
+                    shader.conditions_of(ItemKind::Struct, structure.name()),
                 );
 
                 fs::write(structure_path, structure_content)?;
             }
+
+            // @/modules/<module_name>/alias.<alias_name>.html
+            for alias in &shader.aliases {
+                let alias_path = concat_path(&module_path, &format!("alias.{}.html", alias.name()));
+                let alias_content = generator.generate_alias(
+                    self.pkg_name(),
+                    path.as_ref(),
+                    alias,
+                    &shader.imports,
+                    shader.conditions_of(ItemKind::Alias, alias.name()),
+                );
+
+                fs::write(alias_path, alias_content)?;
+            }
         }
 
         // @/source/<module_name>.html
@@ -332,8 +496,13 @@ impl RegisteredDocument {
         fs::create_dir_all(&source_path)?;
 
         for shader in &self.shaders {
-            let source_file_path =
-                concat_path(&source_path, &format!("{}.html", shader.module_name));
+            let source_file_path = concat_path(
+                &source_path,
+                &format!("{}.html", module_dir(&shader.module_name)),
+            );
+            if let Some(parent) = source_file_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
             let source_content = generator.generate_source(self.pkg_name(), path.as_ref(), shader);
 
             fs::write(source_file_path, source_content)?;
@@ -342,6 +511,12 @@ impl RegisteredDocument {
         Ok(())
     }
 
+    /// Checks the bindings of modules used together by entry points
+    /// for conflicting `@group`/`@binding` slots. See [`binding_conflicts`] for details.
+    pub fn binding_conflicts(&self) -> Vec<BindingConflict> {
+        binding_conflicts(&self.shaders)
+    }
+
     /// Get field `pkg_name` from instance of `RegisteredDocument`.
     pub fn pkg_name(&self) -> &str {
         &self.pkg_name
@@ -368,6 +543,95 @@ impl RegisteredDocument {
     }
 }
 
+/// Resolves the modules of the functions replaced by `override fn`s,
+/// and registers the overriding modules in the overridden `virtual fn`s.
+fn register_overrides(shaders: &mut [Wgsl]) {
+    let mut overrides = vec![];
+
+    for (shader_index, shader) in shaders.iter().enumerate() {
+        for (function_index, function) in shader.functions.iter().enumerate() {
+            let Some(FunctionModifier::Override { module_path, .. }) = function.modifier() else {
+                continue;
+            };
+
+            match resolve_module_path(shaders, shader, module_path) {
+                Some(target_index) => overrides.push((shader_index, function_index, target_index)),
+                None => log::warn!(
+                    "Module `{}` of function `{}` overridden in module `{}` not found!",
+                    module_path,
+                    function.name(),
+                    shader.module_name
+                ),
+            }
+        }
+    }
+
+    for (shader_index, function_index, target_index) in overrides {
+        let module_name = shaders[shader_index].module_name.clone();
+        let name = shaders[shader_index].functions[function_index]
+            .name()
+            .to_owned();
+        let target_module_name = shaders[target_index].module_name.clone();
+
+        let registered = shaders[target_index]
+            .functions
+            .iter_mut()
+            .find(|function| function.name() == name)
+            .is_some_and(|function| function.register_overridden_by(&module_name));
+
+        if registered {
+            shaders[shader_index].functions[function_index]
+                .register_overridden_module(&target_module_name);
+        } else {
+            log::warn!(
+                "Function `{}` overridden in module `{}` is not a virtual function of module `{}`!",
+                name,
+                module_name,
+                target_module_name
+            );
+        }
+    }
+}
+
+/// Returns the index of the module referred to by `module_path` in `shader`:
+/// an import alias, a module import path or the name of a module.
+fn resolve_module_path(shaders: &[Wgsl], shader: &Wgsl, module_path: &str) -> Option<usize> {
+    let module_name = shader
+        .imports
+        .iter()
+        .find(|import| import.registered() && import.name() == module_path)
+        .map_or(module_path, |import| import.module_name());
+
+    shaders.iter().position(|s| s.module_name == module_name)
+}
+
+/// Collects the files of the package `directory`, along with the shaders of its subdirectories,
+/// which form nested modules of the package. Hidden subdirectories are skipped.
+fn collect_package_files(
+    directory: &Path,
+    nested: bool,
+    paths: &mut Vec<PathBuf>,
+) -> Result<(), Error> {
+    for entry in fs::read_dir(directory)?.filter_map(|e| e.ok()) {
+        let path = entry.path();
+
+        if entry.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+            if !entry.file_name().to_string_lossy().starts_with('.') {
+                collect_package_files(&path, true, paths)?;
+            }
+        } else if !nested || is_shader_file(&path) {
+            paths.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+fn is_shader_file(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext == "wgsl" || ext == "wesl")
+}
+
 fn concat_path(path: impl AsRef<Path>, filename: &str) -> PathBuf {
     let mut buf = path.as_ref().to_path_buf();
     buf.push(filename);
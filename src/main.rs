@@ -2,7 +2,9 @@ use clap::Parser;
 use fs_err as fs;
 use log::LevelFilter;
 use std::env;
-use wgsldoc::{cli::Args, generator::TeraGenerator, Document};
+use wgsldoc::{
+    cli::Args, generator::TeraGenerator, models::condition::ShaderDefs, Document, DocumentOptions,
+};
 
 fn main() -> anyhow::Result<()> {
     pretty_env_logger::formatted_builder()
@@ -29,10 +31,18 @@ fn main() -> anyhow::Result<()> {
         args.target_dir = fs::canonicalize(&args.target_dir)?;
     }
 
+    let shader_defs = (!args.shader_defs.is_empty())
+        .then(|| args.shader_defs.iter().cloned().collect::<ShaderDefs>());
+
+    let options = DocumentOptions {
+        shader_defs,
+        recover: args.recover,
+    };
+
     let document = if let Some(directory) = &args.directory {
-        Document::open(args.name, directory.clone())?
+        Document::open_with_options(args.name, directory.clone(), options)?
     } else {
-        Document::open(args.name, env::current_dir()?)?
+        Document::open_with_options(args.name, env::current_dir()?, options)?
     };
 
     if document.shaders().is_empty() {
@@ -52,6 +62,10 @@ fn main() -> anyhow::Result<()> {
         return Ok(());
     }
 
+    if args.check_bindings {
+        return check_bindings_mode(&registered);
+    }
+
     registered.generate(&mut TeraGenerator::new(args.base_url), args.target_dir)?;
 
     Ok(())
@@ -65,6 +79,23 @@ fn ast_only_mode(registered: &wgsldoc::RegisteredDocument) {
     }
 }
 
+fn check_bindings_mode(registered: &wgsldoc::RegisteredDocument) -> anyhow::Result<()> {
+    log::info!("Checking bindings of modules used together for conflicting slots.");
+
+    let conflicts = registered.binding_conflicts();
+
+    for conflict in &conflicts {
+        log::error!("{conflict}");
+    }
+
+    if !conflicts.is_empty() {
+        anyhow::bail!("Found {} conflicting binding slot(s)", conflicts.len());
+    }
+
+    log::info!("No conflicting binding slots found.");
+    Ok(())
+}
+
 fn show_undocumented_mode(registered: &wgsldoc::RegisteredDocument) {
     log::info!("Entering undocumented mode. This will log warnings for any undocumented items in the shaders.");
 
@@ -115,6 +146,16 @@ fn show_undocumented_mode(registered: &wgsldoc::RegisteredDocument) {
             }
         }
 
+        for alias in &shader.aliases {
+            if alias.docs().is_none() {
+                log::warn!(
+                    "Alias '{}' in shader '{}' has no documentation.",
+                    alias.name(),
+                    shader.module_name
+                );
+            }
+        }
+
         for import in &shader.imports {
             if import.docs().is_none() {
                 log::warn!(
@@ -0,0 +1,88 @@
+//! Alias model module used for parsing and representing WGSL type aliases.
+//! Used for generating aliases documentation.
+
+use super::{
+    import::{Import, RegisterImports},
+    types::{RenderedType, Type, TypeKind},
+};
+use crate::{impl_eq_name, models::ComponentInfo, utils::html::to_html};
+
+/// Represents a type alias in a shader module. Example:
+/// ```wgsl
+/// alias Color = vec4<f32>;
+/// ```
+#[derive(Debug)]
+pub struct Alias {
+    docs: Option<String>,
+    name: String,
+    ty: Type,
+}
+
+impl Alias {
+    /// Creates a new Alias instance (usually from parsed elements).
+    pub fn new(docs: Option<String>, name: String, ty: Type) -> Alias {
+        Alias { docs, name, ty }
+    }
+
+    /// Renders the aliased type into a serializable form for templates.
+    pub fn rendered_type(&self, imports: &[Import]) -> RenderedType {
+        self.ty.rendered_type(imports)
+    }
+
+    /// Returns a [`ComponentInfo`] containing a summary of the alias documentation,
+    /// with the summary extracted from the rendered Markdown as HTML.
+    pub fn info_rich_text(&self) -> ComponentInfo {
+        let summary = self.docs.as_deref().map(to_html);
+
+        ComponentInfo::new(self.name.clone(), summary)
+    }
+
+    /// Returns a [`ComponentInfo`] containing a summary of the alias documentation,
+    /// with the summary extracted from the rendered Markdown as plain text. The summary is truncated
+    /// to `ComponentInfo::SUMMARY_MAX_LENGTH` characters if necessary.
+    pub fn info_plain_text(&self) -> ComponentInfo {
+        let summary = self.docs.as_deref().map(|docs| {
+            let html = to_html(docs);
+            let parsed = scraper::Html::parse_fragment(&html);
+
+            let summary = parsed
+                .root_element()
+                .text()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .trim()
+                .to_string();
+
+            ComponentInfo::truncate_summary(summary)
+        });
+
+        ComponentInfo::new(self.name.clone(), summary)
+    }
+
+    /// Get field `docs` from instance of `Alias`.
+    pub fn docs(&self) -> Option<&str> {
+        self.docs.as_deref()
+    }
+
+    /// Get field `name` from instance of `Alias`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get field `type` from instance of `Alias`.
+    pub fn aliased_type(&self) -> &Type {
+        &self.ty
+    }
+}
+
+impl RegisterImports for Alias {
+    fn register_imports(&mut self, imports: &[Import]) {
+        self.ty.register_imports(imports);
+    }
+
+    fn register_same_module_types(&mut self, types: &[(String, TypeKind)]) {
+        self.ty.register_same_module_types(types);
+    }
+}
+
+impl_eq_name!(Alias::name);
@@ -6,7 +6,7 @@ use crate::{
     impl_eq_name,
     models::{
         import::{Import, RegisterImports},
-        types::{RenderedType, Type},
+        types::{AccessMode, AddressSpace, RenderedType, Type, TypeKind},
     },
 };
 
@@ -19,21 +19,19 @@ pub struct Binding {
     docs: Option<String>,
     attr_group: u16,
     attr_binding: u16,
+    address_space: Option<AddressSpace>,
+    access_mode: Option<AccessMode>,
     name: String,
     ty: Type,
 }
 
 impl RegisterImports for Binding {
     fn register_imports(&mut self, imports: &[Import]) {
-        if let Type::Path(ref mut ty) = &mut self.ty {
-            ty.register_imports(imports)
-        }
+        self.ty.register_imports(imports);
     }
 
-    fn register_same_module_types(&mut self, type_names: &[String]) {
-        if let Type::Path(ref mut ty) = &mut self.ty {
-            ty.register_same_module_types(type_names)
-        }
+    fn register_same_module_types(&mut self, types: &[(String, TypeKind)]) {
+        self.ty.register_same_module_types(types);
     }
 }
 
@@ -45,6 +43,8 @@ pub struct RenderedBinding {
     docs: Option<String>,
     attr_group: u16,
     attr_binding: u16,
+    address_space: Option<AddressSpace>,
+    access_mode: Option<AccessMode>,
     name: String,
     ty: RenderedType,
 }
@@ -55,6 +55,8 @@ impl Binding {
         docs: Option<String>,
         attr_group: u16,
         attr_binding: u16,
+        address_space: Option<AddressSpace>,
+        access_mode: Option<AccessMode>,
         name: String,
         ty: Type,
     ) -> Binding {
@@ -62,6 +64,8 @@ impl Binding {
             docs,
             attr_group,
             attr_binding,
+            address_space,
+            access_mode,
             name,
             ty,
         }
@@ -77,6 +81,38 @@ impl Binding {
         &self.name
     }
 
+    /// Get field `attr_group` from instance of `Binding`.
+    pub fn attr_group(&self) -> u16 {
+        self.attr_group
+    }
+
+    /// Get field `attr_binding` from instance of `Binding`.
+    pub fn attr_binding(&self) -> u16 {
+        self.attr_binding
+    }
+
+    /// Get field `address_space` from instance of `Binding`.
+    /// Textures and samplers have no address space.
+    pub fn address_space(&self) -> Option<AddressSpace> {
+        self.address_space
+    }
+
+    /// Get field `access_mode` from instance of `Binding`, as written in the source.
+    pub fn access_mode(&self) -> Option<AccessMode> {
+        self.access_mode
+    }
+
+    /// Returns the access mode the binding actually has: the explicit one,
+    /// or `read` for uniform and storage buffers declared without one.
+    pub fn effective_access_mode(&self) -> Option<AccessMode> {
+        match self.address_space {
+            Some(AddressSpace::Uniform | AddressSpace::Storage) => {
+                Some(self.access_mode.unwrap_or_default())
+            }
+            _ => self.access_mode,
+        }
+    }
+
     /// Get field `type` from instance of `Binding`.
     pub fn binding_type(&self) -> &Type {
         &self.ty
@@ -88,8 +124,10 @@ impl Binding {
             docs: self.docs.clone(),
             attr_group: self.attr_group,
             attr_binding: self.attr_binding,
+            address_space: self.address_space,
+            access_mode: self.effective_access_mode(),
             name: self.name.clone(),
-            ty: self.binding_type().rendered_type(imports, false),
+            ty: self.binding_type().rendered_type(imports),
         }
     }
 }
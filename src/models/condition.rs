@@ -0,0 +1,218 @@
+//! Condition model module used for representing preprocessor conditions and shader defs.
+//! Used for documenting module items that only exist in some shader configurations,
+//! or stripping them when documenting a specific configuration.
+
+use std::{cmp::Ordering, collections::HashMap, fmt::Display};
+
+/// Shader defs of a shader configuration, by name.
+pub type ShaderDefs = HashMap<String, ShaderDefValue>;
+
+/// Represents the value of a shader def, e.g. `SHADOWS=true` or `MAX_LIGHTS=16`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShaderDefValue {
+    /// A boolean value. A shader def set to `false` counts as not defined.
+    Bool(bool),
+    /// A signed integer value.
+    Int(i32),
+    /// An unsigned integer value, written with the `u` suffix (e.g. `16u`).
+    UInt(u32),
+}
+
+impl Default for ShaderDefValue {
+    fn default() -> Self {
+        ShaderDefValue::Bool(true)
+    }
+}
+
+impl Display for ShaderDefValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShaderDefValue::Bool(value) => write!(f, "{value}"),
+            ShaderDefValue::Int(value) => write!(f, "{value}"),
+            ShaderDefValue::UInt(value) => write!(f, "{value}u"),
+        }
+    }
+}
+
+/// Represents a condition of a naga_oil-style conditional block, which an item depends on. Example:
+/// ```wgsl
+/// #ifdef SKINNED
+/// #ifndef SHADOWS
+/// #if MAX_LIGHTS >= 4
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Condition {
+    /// The shader def is defined (`#ifdef NAME`).
+    Defined(String),
+    /// The shader def is not defined (`#ifndef NAME`).
+    NotDefined(String),
+    /// The value of the shader def is compared with a value (`#if NAME == VALUE`).
+    Comparison {
+        /// The name of the shader def.
+        name: String,
+        /// The comparison operator.
+        op: ComparisonOp,
+        /// The value the shader def is compared with.
+        value: String,
+    },
+}
+
+impl Condition {
+    /// Returns the opposite condition, which holds in the `#else` branch of the conditional block.
+    pub fn negated(&self) -> Condition {
+        match self {
+            Condition::Defined(name) => Condition::NotDefined(name.clone()),
+            Condition::NotDefined(name) => Condition::Defined(name.clone()),
+            Condition::Comparison { name, op, value } => Condition::Comparison {
+                name: name.clone(),
+                op: op.negated(),
+                value: value.clone(),
+            },
+        }
+    }
+
+    /// Evaluates the condition for the given shader defs.
+    /// Returns `None` if a compared shader def is not defined,
+    /// or the compared value doesn't match the type of the shader def.
+    pub fn evaluate(&self, shader_defs: &ShaderDefs) -> Option<bool> {
+        let defined = |name: &str| {
+            shader_defs
+                .get(name)
+                .is_some_and(|value| *value != ShaderDefValue::Bool(false))
+        };
+
+        match self {
+            Condition::Defined(name) => Some(defined(name)),
+            Condition::NotDefined(name) => Some(!defined(name)),
+            Condition::Comparison { name, op, value } => {
+                let ordering = match shader_defs.get(name)? {
+                    ShaderDefValue::Bool(def) => def.cmp(&value.parse().ok()?),
+                    ShaderDefValue::Int(def) => def.cmp(&value.parse().ok()?),
+                    ShaderDefValue::UInt(def) => {
+                        def.cmp(&value.trim_end_matches('u').parse().ok()?)
+                    }
+                };
+
+                Some(op.matches(ordering))
+            }
+        }
+    }
+}
+
+impl Display for Condition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Condition::Defined(name) => write!(f, "{name}"),
+            Condition::NotDefined(name) => write!(f, "!{name}"),
+            Condition::Comparison { name, op, value } => write!(f, "{name} {op} {value}"),
+        }
+    }
+}
+
+/// Adds the `conditions` of another branch declaring the same item to the `alternatives` of the item,
+/// any of which makes the item available. Alternatives differing only in a negated condition
+/// (e.g. the `#ifdef` and `#else` branch of a block) are merged into their common conditions,
+/// so an item declared in every branch doesn't depend on the block. An empty alternative means
+/// the item is available unconditionally.
+pub fn add_alternative(alternatives: &mut Vec<Vec<Condition>>, conditions: Vec<Condition>) {
+    let implies = |stronger: &[Condition], weaker: &[Condition]| {
+        weaker.iter().all(|condition| stronger.contains(condition))
+    };
+    let complementary = |a: &[Condition], b: &[Condition]| {
+        let mut only_a = a.iter().filter(|condition| !b.contains(condition));
+        let mut only_b = b.iter().filter(|condition| !a.contains(condition));
+
+        a.len() == b.len()
+            && match (only_a.next(), only_b.next(), only_a.next()) {
+                (Some(a), Some(b), None) => a.negated() == *b,
+                _ => false,
+            }
+    };
+
+    let mut conditions = conditions;
+    loop {
+        if alternatives
+            .iter()
+            .any(|alternative| implies(&conditions, alternative))
+        {
+            return;
+        }
+        alternatives.retain(|alternative| !implies(alternative, &conditions));
+
+        match alternatives
+            .iter()
+            .position(|alternative| complementary(alternative, &conditions))
+        {
+            Some(index) => {
+                let other = alternatives.remove(index);
+                conditions.retain(|condition| other.contains(condition));
+            }
+            None => {
+                alternatives.push(conditions);
+                return;
+            }
+        }
+    }
+}
+
+/// Represents a comparison operator of an `#if` condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComparisonOp {
+    /// `==`
+    Equal,
+    /// `!=`
+    NotEqual,
+    /// `<`
+    Less,
+    /// `<=`
+    LessEqual,
+    /// `>`
+    Greater,
+    /// `>=`
+    GreaterEqual,
+}
+
+impl ComparisonOp {
+    /// Returns the opposite operator, e.g. `>=` for `<`.
+    pub fn negated(&self) -> ComparisonOp {
+        use ComparisonOp::*;
+
+        match self {
+            Equal => NotEqual,
+            NotEqual => Equal,
+            Less => GreaterEqual,
+            LessEqual => Greater,
+            Greater => LessEqual,
+            GreaterEqual => Less,
+        }
+    }
+
+    /// Returns whether the operator holds for the `ordering` of its operands.
+    pub fn matches(&self, ordering: Ordering) -> bool {
+        use ComparisonOp::*;
+
+        match self {
+            Equal => ordering.is_eq(),
+            NotEqual => ordering.is_ne(),
+            Less => ordering.is_lt(),
+            LessEqual => ordering.is_le(),
+            Greater => ordering.is_gt(),
+            GreaterEqual => ordering.is_ge(),
+        }
+    }
+}
+
+impl Display for ComparisonOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use ComparisonOp::*;
+
+        match self {
+            Equal => write!(f, "=="),
+            NotEqual => write!(f, "!="),
+            Less => write!(f, "<"),
+            LessEqual => write!(f, "<="),
+            Greater => write!(f, ">"),
+            GreaterEqual => write!(f, ">="),
+        }
+    }
+}
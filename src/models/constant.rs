@@ -1,9 +1,9 @@
 //! Constant model module used for parsing and representing WGSL constants.
-//! Used for generating `const`s documentation.
+//! Used for generating `const`s and `override`s documentation.
 
 use serde::Serialize;
 use crate::models::import::{Import, RegisterImports};
-use crate::models::types::{RenderedType, Type};
+use crate::models::types::{RenderedType, Type, TypeKind};
 use crate::{impl_eq_name, models::ComponentInfo, utils::html::to_html};
 
 /// Represents a binding in a shader module. Example:
@@ -63,17 +63,7 @@ impl Constant {
         RenderedConstant {
             docs: self.docs.clone(),
             name: self.name.clone(),
-            ty: self.constant_type().map(|ty| match ty {
-                Type::Primitive(p) => RenderedType {
-                    name: p.to_string(),
-                    ..Default::default()
-                },
-                Type::Vector(v) => RenderedType {
-                    name: v.to_string(),
-                    ..Default::default()
-                },
-                Type::Path(path) => Type::Path(path.clone()).rendered_type(imports, false),
-            }),
+            ty: self.constant_type().map(|ty| ty.rendered_type(imports)),
             value: self.value.clone(),
         }
     }
@@ -94,11 +84,7 @@ impl Constant {
                 .trim()
                 .to_string();
 
-            if summary.len() > ComponentInfo::SUMMARY_MAX_LENGTH {
-                format!("{}...", &summary[..ComponentInfo::SUMMARY_MAX_LENGTH])
-            } else {
-                summary
-            }
+            ComponentInfo::truncate_summary(summary)
         });
 
         ComponentInfo::new(self.name.clone(), summary)
@@ -107,16 +93,110 @@ impl Constant {
 
 impl RegisterImports for Constant {
     fn register_imports(&mut self, imports: &[Import]) {
-        if let Some(Type::Path(ref mut ty)) = &mut self.ty {
+        if let Some(ty) = &mut self.ty {
             ty.register_imports(imports)
         }
     }
 
-    fn register_same_module_types(&mut self, type_names: &[String]) {
-        if let Some(Type::Path(ref mut ty)) = &mut self.ty {
-            ty.register_same_module_types(type_names)
+    fn register_same_module_types(&mut self, types: &[(String, TypeKind)]) {
+        if let Some(ty) = &mut self.ty {
+            ty.register_same_module_types(types)
         }
     }
 }
 
 impl_eq_name!(Constant::name);
+
+/// Represents a pipeline-overridable constant in a shader module. Example:
+/// ```wgsl
+/// @id(0) override BLOCK_SIZE: u32 = 64;
+/// ```
+#[derive(Debug)]
+pub struct Override {
+    docs: Option<String>,
+    id: Option<u16>,
+    name: String,
+    ty: Option<Type>,
+    value: Option<String>,
+}
+
+/// A serializable representation of an override for rendering purposes used in Tera.
+#[derive(Serialize, Debug)]
+pub struct RenderedOverride {
+    docs: Option<String>,
+    id: Option<u16>,
+    name: String,
+    ty: Option<RenderedType>,
+    value: Option<String>,
+}
+
+impl Override {
+    /// Creates a new Override instance (usually from parsed elements).
+    pub fn new(
+        docs: Option<String>,
+        id: Option<u16>,
+        name: String,
+        ty: Option<Type>,
+        value: Option<String>,
+    ) -> Override {
+        Override {
+            docs,
+            id,
+            name,
+            ty,
+            value,
+        }
+    }
+
+    /// Get field `docs` from instance of `Override`.
+    pub fn docs(&self) -> Option<&str> {
+        self.docs.as_deref()
+    }
+
+    /// Get field `id` from instance of `Override`.
+    pub fn id(&self) -> Option<u16> {
+        self.id
+    }
+
+    /// Get field `name` from instance of `Override`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get field `type` from instance of `Override`.
+    pub fn override_type(&self) -> Option<&Type> {
+        self.ty.as_ref()
+    }
+
+    /// Get field `value` (the default value) from instance of `Override`.
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    /// Renders the override into a serializable form for templates.
+    pub fn rendered(&self, imports: &[Import]) -> RenderedOverride {
+        RenderedOverride {
+            docs: self.docs.clone(),
+            id: self.id,
+            name: self.name.clone(),
+            ty: self.override_type().map(|ty| ty.rendered_type(imports)),
+            value: self.value.clone(),
+        }
+    }
+}
+
+impl RegisterImports for Override {
+    fn register_imports(&mut self, imports: &[Import]) {
+        if let Some(ty) = &mut self.ty {
+            ty.register_imports(imports)
+        }
+    }
+
+    fn register_same_module_types(&mut self, types: &[(String, TypeKind)]) {
+        if let Some(ty) = &mut self.ty {
+            ty.register_same_module_types(types)
+        }
+    }
+}
+
+impl_eq_name!(Override::name);
@@ -0,0 +1,71 @@
+//! Directive model module used for parsing and representing WGSL global directives.
+//! Used for documenting which extensions and language features a module depends on.
+
+use serde::Serialize;
+use std::fmt::Display;
+
+/// Represents a global directive in a shader module. Example:
+/// ```wgsl
+/// enable f16;
+/// requires readonly_and_readwrite_storage_textures;
+/// diagnostic(off, derivative_uniformity);
+/// ```
+#[derive(Debug)]
+pub enum Directive {
+    /// An `enable` directive, listing the enabled extensions.
+    Enable(Vec<String>),
+    /// A `requires` directive, listing the required language features.
+    Requires(Vec<String>),
+    /// A global `diagnostic` directive, changing the severity of a diagnostic rule.
+    Diagnostic(Diagnostic),
+}
+
+/// Represents a diagnostic control, e.g. `diagnostic(off, derivative_uniformity)`.
+#[derive(Debug, Serialize)]
+pub struct Diagnostic {
+    severity: DiagnosticSeverity,
+    rule: String,
+}
+
+impl Diagnostic {
+    /// Creates a new Diagnostic instance (usually from parsed elements).
+    pub fn new(severity: DiagnosticSeverity, rule: String) -> Diagnostic {
+        Diagnostic { severity, rule }
+    }
+
+    /// Get field `severity` from instance of `Diagnostic`.
+    pub fn severity(&self) -> &DiagnosticSeverity {
+        &self.severity
+    }
+
+    /// Get field `rule` from instance of `Diagnostic`.
+    pub fn rule(&self) -> &str {
+        &self.rule
+    }
+}
+
+/// Represents the severity of a diagnostic rule.
+#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiagnosticSeverity {
+    /// The diagnostic is reported as an error.
+    #[default]
+    Error,
+    /// The diagnostic is reported as a warning.
+    Warning,
+    /// The diagnostic is reported as an info message.
+    Info,
+    /// The diagnostic is disabled.
+    Off,
+}
+
+impl Display for DiagnosticSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiagnosticSeverity::Error => write!(f, "error"),
+            DiagnosticSeverity::Warning => write!(f, "warning"),
+            DiagnosticSeverity::Info => write!(f, "info"),
+            DiagnosticSeverity::Off => write!(f, "off"),
+        }
+    }
+}
@@ -3,13 +3,16 @@
 
 use super::{
     import::{Import, RegisterImports},
-    types::{PathType, Primitive, Type, Vector},
+    structure::MemberAttribute,
+    types::{RenderedType, Type, TypeKind},
 };
 use crate::{
     impl_eq_name,
-    models::{types::RenderedType, ComponentInfo, RenderedArgField},
+    models::{ComponentInfo, RenderedArgField},
     utils::html::to_html,
 };
+use serde::Serialize;
+use std::fmt::Display;
 
 /// Represents a function in a shader module. Example:
 /// ```wgsl
@@ -21,24 +24,40 @@ use crate::{
 #[derive(Debug)]
 pub struct Function {
     docs: Option<String>,
+    stage: Option<ShaderStage>,
+    workgroup_size: Vec<String>,
+    modifier: Option<FunctionModifier>,
     name: String,
     args: Vec<Arg>,
     return_ty: Option<Type>,
+    return_attributes: Vec<MemberAttribute>,
+    references: Vec<String>,
 }
 
 impl Function {
     /// Creates a new Function instance (usually from parsed elements).
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         docs: Option<String>,
+        stage: Option<ShaderStage>,
+        workgroup_size: Vec<String>,
+        modifier: Option<FunctionModifier>,
         name: String,
         args: Vec<Arg>,
         return_ty: Option<Type>,
+        return_attributes: Vec<MemberAttribute>,
+        references: Vec<String>,
     ) -> Function {
         Function {
             docs,
+            stage,
+            workgroup_size,
+            modifier,
             name,
             args,
             return_ty,
+            return_attributes,
+            references,
         }
     }
 
@@ -47,32 +66,53 @@ impl Function {
         self.args()
             .iter()
             .map(|arg| {
-                let ty = match arg.argument_type() {
-                    FunctionType::Primitive(p) => RenderedType {
-                        name: p.to_string(),
-                        ..Default::default()
-                    },
-                    FunctionType::Vector(v) => RenderedType {
-                        name: v.to_string(),
-                        ..Default::default()
-                    },
-                    FunctionType::Path(path) => {
-                        Type::Path(path.clone()).rendered_type(imports, false)
-                    }
-                    FunctionType::FunctionPointer(inner_ty) => {
-                        inner_ty.rendered_type(imports, true)
-                    }
-                };
+                let ty = arg.argument_type().rendered_type(imports);
 
                 RenderedArgField {
                     docs: arg.docs().map(to_html),
                     name: arg.name().to_string(),
                     ty,
+                    attributes: arg.attributes().iter().map(|a| a.to_string()).collect(),
                 }
             })
             .collect()
     }
 
+    /// Renders the function's summary, stage, workgroup size and modifier
+    /// into a serializable form for templates.
+    pub fn rendered_info(&self) -> RenderedFunctionInfo {
+        RenderedFunctionInfo {
+            info: self.info_plain_text(),
+            stage: self.stage,
+            workgroup_size: self.workgroup_size.clone(),
+            modifier: self.modifier.clone(),
+        }
+    }
+
+    /// Renders the function as an entry point of module `module_name`
+    /// into a serializable form for templates.
+    pub fn rendered_entry_point(
+        &self,
+        module_name: &str,
+        imports: &[Import],
+    ) -> RenderedEntryPoint {
+        RenderedEntryPoint {
+            module: module_name.to_string(),
+            info: self.rendered_info(),
+            args: self.rendered_args(imports),
+            return_type: self.return_type().map(|ty| ty.rendered_type(imports)),
+            return_attributes: self.rendered_return_attributes(),
+        }
+    }
+
+    /// Renders the IO attributes of the function's return value into a serializable form for templates.
+    pub fn rendered_return_attributes(&self) -> Vec<String> {
+        self.return_attributes
+            .iter()
+            .map(|a| a.to_string())
+            .collect()
+    }
+
     /// Returns a [`ComponentInfo`] containing a summary of the function documentation,
     /// with the summary extracted from the rendered Markdown as HTML.
     pub fn info_rich_text(&self) -> ComponentInfo {
@@ -97,11 +137,7 @@ impl Function {
                 .trim()
                 .to_string();
 
-            if summary.len() > ComponentInfo::SUMMARY_MAX_LENGTH {
-                format!("{}...", &summary[..ComponentInfo::SUMMARY_MAX_LENGTH])
-            } else {
-                summary
-            }
+            ComponentInfo::truncate_summary(summary)
         });
 
         ComponentInfo::new(self.name.clone(), summary)
@@ -112,6 +148,58 @@ impl Function {
         self.docs.as_deref()
     }
 
+    /// Get field `stage` from instance of `Function`.
+    pub fn stage(&self) -> Option<ShaderStage> {
+        self.stage
+    }
+
+    /// Get field `workgroup_size` from instance of `Function`.
+    /// Each dimension is a literal or a const/override expression.
+    pub fn workgroup_size(&self) -> &[String] {
+        &self.workgroup_size
+    }
+
+    /// Get field `modifier` from instance of `Function`.
+    pub fn modifier(&self) -> Option<&FunctionModifier> {
+        self.modifier.as_ref()
+    }
+
+    /// Returns `true` if the function is declared with the `virtual` modifier.
+    pub fn is_virtual(&self) -> bool {
+        matches!(self.modifier, Some(FunctionModifier::Virtual { .. }))
+    }
+
+    /// Registers `module_name` as a module overriding the function, if it is virtual.
+    pub fn register_overridden_by(&mut self, module_name: &str) -> bool {
+        match &mut self.modifier {
+            Some(FunctionModifier::Virtual { overridden_by }) => {
+                if !overridden_by.iter().any(|m| m == module_name) {
+                    overridden_by.push(module_name.to_owned());
+                }
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Registers `module_name` as the resolved module of the function overridden by the function,
+    /// if it is an override.
+    pub fn register_overridden_module(&mut self, module_name: &str) -> bool {
+        match &mut self.modifier {
+            Some(FunctionModifier::Override { module, .. }) => {
+                *module = Some(module_name.to_owned());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the function is a shader entry point.
+    pub fn is_entry_point(&self) -> bool {
+        self.stage.is_some()
+    }
+
     /// Get field `name` from instance of `Function`.
     pub fn name(&self) -> &str {
         &self.name
@@ -126,6 +214,19 @@ impl Function {
     pub fn return_type(&self) -> Option<&Type> {
         self.return_ty.as_ref()
     }
+
+    /// Get field `return_attributes` from instance of `Function`.
+    /// Contains the IO attributes of entry point outputs, e.g. `@location(0)`.
+    pub fn return_attributes(&self) -> &[MemberAttribute] {
+        &self.return_attributes
+    }
+
+    /// Get field `references` from instance of `Function`.
+    /// Contains identifiers referenced in the function body, such as
+    /// bindings or called functions, optionally module-qualified (`utils::helper`).
+    pub fn references(&self) -> &[String] {
+        &self.references
+    }
 }
 
 impl RegisterImports for Function {
@@ -134,40 +235,143 @@ impl RegisterImports for Function {
             arg.register_imports(imports)
         }
 
-        if let Some(Type::Path(path_type)) = &mut self.return_ty {
-            path_type.register_imports(imports);
+        if let Some(return_ty) = &mut self.return_ty {
+            return_ty.register_imports(imports);
         }
     }
 
-    fn register_same_module_types(&mut self, type_names: &[String]) {
+    fn register_same_module_types(&mut self, types: &[(String, TypeKind)]) {
         for arg in &mut self.args {
-            arg.register_same_module_types(type_names);
+            arg.register_same_module_types(types);
         }
 
-        if let Some(Type::Path(path_type)) = &mut self.return_ty {
-            path_type.register_same_module_types(type_names);
+        if let Some(return_ty) = &mut self.return_ty {
+            return_ty.register_same_module_types(types);
         }
     }
 }
 
 impl_eq_name!(Function::name);
 
+/// naga_oil modifier of a function, allowing to replace a function of one module
+/// with a function of another module. Example:
+/// ```wgsl
+/// virtual fn point_light(light_id: u32) -> vec3<f32> { ... }
+/// override fn bevy_pbr::lighting::point_light(light_id: u32) -> vec3<f32> { ... }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FunctionModifier {
+    /// A `virtual` function, which may be overridden in other modules.
+    Virtual {
+        /// The names of the modules overriding the function, filled in during registration.
+        overridden_by: Vec<String>,
+    },
+    /// An `override` function, replacing a virtual function of another module.
+    Override {
+        /// The path of the module declaring the overridden function, as written.
+        module_path: String,
+        /// The name of the module declaring the overridden function, resolved during registration.
+        module: Option<String>,
+    },
+}
+
+/// Shader stage of an entry point function.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ShaderStage {
+    /// Vertex shader (`@vertex`).
+    Vertex,
+    /// Fragment shader (`@fragment`).
+    Fragment,
+    /// Compute shader (`@compute`).
+    Compute,
+}
+
+impl Display for ShaderStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShaderStage::Vertex => write!(f, "vertex"),
+            ShaderStage::Fragment => write!(f, "fragment"),
+            ShaderStage::Compute => write!(f, "compute"),
+        }
+    }
+}
+
+/// A serializable summary of a function for rendering purposes used in Tera,
+/// extending its [`ComponentInfo`] with the entry point stage, workgroup size and modifier.
+#[derive(Debug, Serialize)]
+pub struct RenderedFunctionInfo {
+    #[serde(flatten)]
+    info: ComponentInfo,
+    stage: Option<ShaderStage>,
+    workgroup_size: Vec<String>,
+    modifier: Option<FunctionModifier>,
+}
+
+/// A serializable representation of an entry point function for rendering purposes
+/// used in Tera, extending its [`RenderedFunctionInfo`] with the owning module,
+/// inputs and outputs.
+#[derive(Debug, Serialize)]
+pub struct RenderedEntryPoint {
+    module: String,
+    #[serde(flatten)]
+    info: RenderedFunctionInfo,
+    args: Vec<RenderedArgField>,
+    return_type: Option<RenderedType>,
+    return_attributes: Vec<String>,
+}
 
 /// Represents a function argument in a function. Example:
 /// ```wgsl
 /// arg1: vec3<f32>
 /// ```
+/// ```wgsl
+/// @builtin(global_invocation_id) id: vec3<u32>
+/// ```
 #[derive(Debug)]
 pub struct Arg {
     docs: Option<String>,
+    attributes: Vec<MemberAttribute>,
     name: String,
     ty: FunctionType,
 }
 
 impl Arg {
     /// Creates a new Arg instance (usually from parsed elements).
-    pub fn new(docs: Option<String>, name: String, ty: FunctionType) -> Arg {
-        Arg { docs, name, ty }
+    pub fn new(
+        docs: Option<String>,
+        attributes: Vec<MemberAttribute>,
+        name: String,
+        ty: FunctionType,
+    ) -> Arg {
+        Arg {
+            docs,
+            attributes,
+            name,
+            ty,
+        }
+    }
+
+    /// Get field `attributes` from instance of `Arg`.
+    pub fn attributes(&self) -> &[MemberAttribute] {
+        &self.attributes
+    }
+
+    /// Returns the IO location of the argument, if it has a `@location` attribute.
+    pub fn location(&self) -> Option<u32> {
+        self.attributes.iter().find_map(|attr| match attr {
+            MemberAttribute::Location(location) => Some(*location),
+            _ => None,
+        })
+    }
+
+    /// Returns the builtin value of the argument, if it has a `@builtin` attribute.
+    pub fn builtin(&self) -> Option<&str> {
+        self.attributes.iter().find_map(|attr| match attr {
+            MemberAttribute::Builtin(builtin) => Some(builtin.as_str()),
+            _ => None,
+        })
     }
 
     /// Get field `docs` from instance of `Arg`.
@@ -188,43 +392,16 @@ impl Arg {
 
 impl RegisterImports for Arg {
     fn register_imports(&mut self, imports: &[Import]) {
-        match &mut self.ty {
-            FunctionType::FunctionPointer(Type::Path(ref mut path_type)) => {
-                path_type.register_imports(imports);
-            }
-            FunctionType::Path(ref mut ty) => ty.register_imports(imports),
-            _ => {}
-        }
+        self.ty.register_imports(imports);
     }
 
-    fn register_same_module_types(&mut self, type_names: &[String]) {
-        match &mut self.ty {
-            FunctionType::FunctionPointer(Type::Path(ref mut path_type)) => {
-                path_type.register_same_module_types(type_names);
-            }
-            FunctionType::Path(ref mut ty) => ty.register_same_module_types(type_names),
-            _ => {}
-        }
+    fn register_same_module_types(&mut self, types: &[(String, TypeKind)]) {
+        self.ty.register_same_module_types(types);
     }
 }
 
 impl_eq_name!(Arg::name);
 
-/// Another variation of [`Type`] used specifically for function arguments.
-#[derive(Debug)]
-pub enum FunctionType {
-    /// Primitive type (e.g., `i32`, `f32`, `bool`).
-    Primitive(Primitive),
-    /// Vector type (e.g., `vec2<T>`, `vec3<T>`, `vec4<T>`).
-    Vector(Vector),
-    /// Path type (e.g., `MyType`, `Module::MyType`).
-    Path(PathType),
-    /// Function pointer type (pointer to data used only in function arguments) (e.g., `ptr<function, Ray::HitRecord>`).
-    FunctionPointer(Type),
-}
-
-impl Default for FunctionType {
-    fn default() -> Self {
-        FunctionType::Primitive(Primitive::default())
-    }
-}
+/// Type of a function argument. Pointers (e.g., `ptr<function, T>`) are regular
+/// [`Type`]s, so function arguments share the same representation.
+pub type FunctionType = Type;
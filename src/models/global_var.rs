@@ -0,0 +1,105 @@
+//! Global variable model module used for parsing and representing module-scope WGSL variables
+//! declared without resource bindings. Used for generating global variables documentation.
+
+use crate::{
+    impl_eq_name,
+    models::{
+        import::{Import, RegisterImports},
+        types::{AddressSpace, RenderedType, Type, TypeKind},
+    },
+};
+use serde::Serialize;
+
+/// Represents a module-scope variable without `@group`/`@binding` attributes. Example:
+/// ```wgsl
+/// var<workgroup> tile: array<f32, 256>;
+/// ```
+#[derive(Debug)]
+pub struct GlobalVar {
+    docs: Option<String>,
+    address_space: AddressSpace,
+    name: String,
+    ty: Option<Type>,
+    value: Option<String>,
+}
+
+/// A serializable representation of a global variable for rendering purposes used in Tera.
+#[derive(Debug, Serialize)]
+pub struct RenderedGlobalVar {
+    docs: Option<String>,
+    address_space: AddressSpace,
+    name: String,
+    ty: Option<RenderedType>,
+    value: Option<String>,
+}
+
+impl GlobalVar {
+    /// Creates a new GlobalVar instance (usually from parsed elements).
+    pub fn new(
+        docs: Option<String>,
+        address_space: AddressSpace,
+        name: String,
+        ty: Option<Type>,
+        value: Option<String>,
+    ) -> GlobalVar {
+        GlobalVar {
+            docs,
+            address_space,
+            name,
+            ty,
+            value,
+        }
+    }
+
+    /// Get field `docs` from instance of `GlobalVar`.
+    pub fn docs(&self) -> Option<&str> {
+        self.docs.as_deref()
+    }
+
+    /// Get field `address_space` from instance of `GlobalVar`.
+    pub fn address_space(&self) -> AddressSpace {
+        self.address_space
+    }
+
+    /// Get field `name` from instance of `GlobalVar`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get field `type` from instance of `GlobalVar`.
+    pub fn var_type(&self) -> Option<&Type> {
+        self.ty.as_ref()
+    }
+
+    /// Get field `value` (the initializer) from instance of `GlobalVar`.
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    /// Renders the global variable into a serializable form for templates.
+    pub fn rendered(&self, imports: &[Import]) -> RenderedGlobalVar {
+        RenderedGlobalVar {
+            docs: self.docs.clone(),
+            address_space: self.address_space,
+            name: self.name.clone(),
+            ty: self.var_type().map(|ty| ty.rendered_type(imports)),
+            value: self.value.clone(),
+        }
+    }
+}
+
+impl RegisterImports for GlobalVar {
+    fn register_imports(&mut self, imports: &[Import]) {
+        if let Some(ty) = &mut self.ty {
+            ty.register_imports(imports)
+        }
+    }
+
+    fn register_same_module_types(&mut self, types: &[(String, TypeKind)]) {
+        if let Some(ty) = &mut self.ty {
+            ty.register_same_module_types(types)
+        }
+    }
+}
+
+impl_eq_name!(GlobalVar::name);
@@ -1,20 +1,34 @@
 //! Import model module used for parsing and representing WGSL imports.
 //! Used for generating imports documentation.
 
-use crate::{impl_eq_name, models::ComponentInfo, utils::html::to_html};
+use crate::{
+    impl_eq_name,
+    models::{types::TypeKind, ComponentInfo},
+    utils::{html::to_html, path::module_name},
+};
 use serde::Serialize;
 use std::collections::HashSet;
+use std::fmt::Display;
 use std::path::{Path, PathBuf};
 
 /// Represents an import in a shader module. Example:
 /// ```wgsl
 /// import "module.wgsl" as MyModule;
 /// ```
+/// or a naga_oil-style import by module path, optionally with a list of imported items:
+/// ```wgsl
+/// #import bevy_pbr::mesh_view_bindings
+/// #import bevy_pbr::forward_io::{Vertex, VertexOutput}
+/// ```
 #[derive(Debug, Clone, Serialize)]
 pub struct Import {
     docs: Option<String>,
     path: PathBuf,
+    module_path: Option<String>,
+    items: Vec<ImportedItem>,
     module_name: String,
+    #[serde(skip)]
+    module_items: Option<ModuleItems>,
     name: String,
     registered: bool,
 }
@@ -24,17 +38,43 @@ impl Import {
     pub fn new(docs: Option<String>, path: PathBuf, name: String) -> Import {
         Import {
             docs: docs.map(|s| to_html(&s)),
-            module_name: path
-                .file_stem()
-                .and_then(|s| s.to_str())
-                .unwrap_or_default()
-                .to_string(),
+            module_name: module_name(&path),
             path,
+            module_path: None,
+            items: vec![],
+            module_items: None,
             name,
             registered: false,
         }
     }
 
+    /// Creates a new Import instance of a module path (e.g. `bevy_pbr::forward_io`),
+    /// importing either the whole module or only the listed `items`.
+    /// Unless an `alias` is given, the module is named after the last path segment.
+    pub fn builtin(
+        docs: Option<String>,
+        module_path: String,
+        alias: Option<String>,
+        items: Vec<String>,
+    ) -> Import {
+        let last_segment = module_path
+            .rsplit("::")
+            .next()
+            .unwrap_or_default()
+            .to_string();
+
+        Import {
+            docs: docs.map(|s| to_html(&s)),
+            path: PathBuf::new(),
+            items: items.into_iter().map(ImportedItem::new).collect(),
+            module_name: last_segment.clone(),
+            module_items: None,
+            name: alias.unwrap_or(last_segment),
+            module_path: Some(module_path),
+            registered: false,
+        }
+    }
+
     /// Get field `module_name` from instance of `Import`.
     pub fn module_name(&self) -> &str {
         &self.module_name
@@ -64,27 +104,111 @@ impl Import {
                 .trim()
                 .to_string();
 
-            if summary.len() > ComponentInfo::SUMMARY_MAX_LENGTH {
-                format!("{}...", &summary[..ComponentInfo::SUMMARY_MAX_LENGTH])
-            } else {
-                summary
-            }
+            ComponentInfo::truncate_summary(summary)
         });
 
         ComponentInfo::new(self.name.clone(), summary)
     }
 
-    /// Registers the import if its path is found in the provided file registry.
-    pub fn register(&mut self, file_registry: &HashSet<PathBuf>) -> bool {
+    /// Registers the import if its path is found in the provided file registry,
+    /// keeping the items of the imported module from `modules`.
+    /// Imports by module path are registered with [`Import::register_module_path`] instead.
+    pub fn register(&mut self, file_registry: &HashSet<PathBuf>, modules: &[ModuleItems]) -> bool {
+        if self.module_path.is_some() {
+            return false;
+        }
+
         for file in file_registry {
             if file.ends_with(self.path()) {
                 self.registered = true;
             }
         }
 
+        if self.registered {
+            self.module_items = modules
+                .iter()
+                .find(|m| m.module_name == self.module_name)
+                .cloned();
+        }
+
+        self.registered
+    }
+
+    /// Registers an import by module path if it refers to one of the `modules`,
+    /// resolving the kinds of the imported items.
+    ///
+    /// The path is matched against module names and import paths declared with `#define_import_path`.
+    /// Leading WESL relative segments are resolved first: `package::` refers to the root shared
+    /// by the modules of the document, and every `super::` to the parent of the `importer` module path.
+    /// A path that doesn't refer to a module, but to an item of the module before it
+    /// (e.g. `bevy_pbr::forward_io::VertexOutput`), imports that single item.
+    pub fn register_module_path(&mut self, modules: &[ModuleItems], importer: &str) -> bool {
+        let Some(module_path) = &self.module_path else {
+            return false;
+        };
+
+        let Some(segments) = resolve_relative_path(module_path, importer) else {
+            log::warn!(
+                "Import path `{}` of module `{}` reaches beyond the package root!",
+                module_path,
+                importer
+            );
+            return false;
+        };
+        let find_module = |path: &[&str]| {
+            let full_path = path.join("::");
+
+            modules.iter().find(|m| m.module_name == full_path)
+        };
+
+        let module = match find_module(&segments) {
+            Some(module) => module,
+            None if segments.len() > 1 && self.items.is_empty() => {
+                let (item, parent) = segments.split_last().unwrap_or((&"", &[]));
+                let Some(module) = find_module(parent) else {
+                    return false;
+                };
+
+                if module.item_kind(item).is_none() {
+                    return false;
+                }
+
+                // The displayed path keeps its relative segments
+                let parent_path = module_path
+                    .rsplit_once("::")
+                    .map_or(module_path.as_str(), |(parent, _)| parent)
+                    .to_string();
+
+                self.items = vec![ImportedItem::new(item.to_string())];
+                self.name = parent.last().unwrap_or(item).to_string();
+                self.module_path = Some(parent_path);
+                module
+            }
+            None => return false,
+        };
+
+        for item in &mut self.items {
+            item.kind = module.item_kind(&item.name);
+        }
+
+        self.module_name = module.module_name.clone();
+        self.module_items = Some(module.clone());
+        self.registered = true;
+
         self.registered
     }
 
+    /// Returns the kind of the item named `name` declared in the imported module,
+    /// whether the import lists it or not. Only known once the import is registered.
+    pub fn module_item_kind(&self, name: &str) -> Option<ItemKind> {
+        self.module_items.as_ref()?.item_kind(name)
+    }
+
+    /// Returns the imported item named `name`, if the import lists it.
+    pub fn item(&self, name: &str) -> Option<&ImportedItem> {
+        self.items.iter().find(|item| item.name == name)
+    }
+
     /// Get field `docs` from instance of `Import`.
     pub fn docs(&self) -> Option<&str> {
         self.docs.as_deref()
@@ -95,6 +219,18 @@ impl Import {
         &self.path
     }
 
+    /// Get field `module_path` from instance of `Import`.
+    /// Only imports by module path (e.g. `#import bevy_pbr::forward_io`) have one.
+    pub fn module_path(&self) -> Option<&str> {
+        self.module_path.as_deref()
+    }
+
+    /// Get field `items` from instance of `Import`.
+    /// Empty if the whole module is imported.
+    pub fn items(&self) -> &[ImportedItem] {
+        &self.items
+    }
+
     /// Get field `name` from instance of `Import`.
     pub fn name(&self) -> &str {
         &self.name
@@ -108,12 +244,135 @@ impl Import {
 
 impl_eq_name!(Import::name);
 
+/// An item imported by name from a module, like `VertexOutput` in
+/// `#import bevy_pbr::forward_io::{VertexOutput}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImportedItem {
+    name: String,
+    kind: Option<ItemKind>,
+}
+
+impl ImportedItem {
+    /// Creates a new ImportedItem instance, whose kind is unknown until the import is registered.
+    pub fn new(name: String) -> ImportedItem {
+        ImportedItem { name, kind: None }
+    }
+
+    /// Get field `name` from instance of `ImportedItem`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get field `kind` from instance of `ImportedItem`.
+    pub fn kind(&self) -> Option<ItemKind> {
+        self.kind
+    }
+}
+
+/// Kind of an item declared in a module.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemKind {
+    /// A function, documented on a `fn.<name>.html` page.
+    #[serde(rename = "fn")]
+    Function,
+    /// A structure, documented on a `struct.<Name>.html` page.
+    Struct,
+    /// A type alias, documented on an `alias.<Name>.html` page.
+    Alias,
+    /// A constant, documented on the module page.
+    Constant,
+    /// A pipeline-overridable constant, documented on the module page.
+    Override,
+    /// A resource binding, documented on the module page.
+    Binding,
+    /// A module-scope variable, documented on the module page.
+    GlobalVar,
+}
+
+impl Display for ItemKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use ItemKind::*;
+
+        match self {
+            Function => write!(f, "fn"),
+            Struct => write!(f, "struct"),
+            Alias => write!(f, "alias"),
+            Constant => write!(f, "constant"),
+            Override => write!(f, "override"),
+            Binding => write!(f, "binding"),
+            GlobalVar => write!(f, "global_var"),
+        }
+    }
+}
+
+impl ItemKind {
+    /// Returns the [`TypeKind`] of the item, if it is a user-defined type.
+    pub fn type_kind(&self) -> Option<TypeKind> {
+        match self {
+            ItemKind::Struct => Some(TypeKind::Struct),
+            ItemKind::Alias => Some(TypeKind::Alias),
+            _ => None,
+        }
+    }
+}
+
+/// Resolves the leading WESL relative segments (`package::`, `super::`) of `module_path`
+/// imported by the module `importer`, returning the segments of the absolute path.
+/// Returns `None` if the path refers to a parent of the package root.
+fn resolve_relative_path<'a>(module_path: &'a str, importer: &'a str) -> Option<Vec<&'a str>> {
+    let mut segments = module_path.split("::").peekable();
+
+    match segments.peek() {
+        Some(&"package") => Some(segments.skip(1).collect()),
+        Some(&"super") => {
+            let mut path = importer.split("::").collect::<Vec<_>>();
+
+            while segments.next_if_eq(&"super").is_some() {
+                path.pop()?;
+            }
+            path.extend(segments);
+
+            Some(path)
+        }
+        _ => Some(segments.collect()),
+    }
+}
+
+/// Names and kinds of the items declared in a module, used to register imports by module path.
+#[derive(Debug, Clone)]
+pub struct ModuleItems {
+    module_name: String,
+    items: Vec<(String, ItemKind)>,
+}
+
+impl ModuleItems {
+    /// Creates a new ModuleItems instance.
+    pub fn new(module_name: String, items: Vec<(String, ItemKind)>) -> ModuleItems {
+        ModuleItems { module_name, items }
+    }
+
+    /// Get field `module_name` from instance of `ModuleItems`.
+    pub fn module_name(&self) -> &str {
+        &self.module_name
+    }
+
+    /// Returns the kind of the item named `name`, if the module declares it.
+    pub fn item_kind(&self, name: &str) -> Option<ItemKind> {
+        self.items
+            .iter()
+            .find(|(item, _)| item == name)
+            .map(|(_, kind)| *kind)
+    }
+}
+
 /// Trait for registering imports in types that may reference other types.
 /// This helps creating reference links in documentation by tracking which types are imported.
 pub trait RegisterImports {
     /// Registers the imports used by the type.
     fn register_imports(&mut self, imports: &[Import]);
 
-    /// Registers the types from the same module as the type.
-    fn register_same_module_types(&mut self, type_names: &[String]);
+    /// Registers the types (structures and aliases) from the same module as the type,
+    /// given as pairs of type name and its kind.
+    fn register_same_module_types(&mut self, types: &[(String, TypeKind)]);
 }
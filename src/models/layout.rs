@@ -0,0 +1,471 @@
+//! Bind group layout module used for merging the bindings of all shader modules.
+//! Used for generating the package-wide bind group layout documentation.
+
+use super::{
+    binding::{Binding, RenderedBinding},
+    function::{Function, ShaderStage},
+    types::{ImportModule, Type},
+    Wgsl,
+};
+use serde::Serialize;
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Display,
+};
+
+/// Bind group layout of a whole package: bindings from every module,
+/// grouped by `@group` and ordered by `@binding`.
+#[derive(Debug)]
+pub struct BindGroupLayout<'a> {
+    groups: BTreeMap<u16, Vec<LayoutBinding<'a>>>,
+}
+
+impl<'a> BindGroupLayout<'a> {
+    /// Creates a new BindGroupLayout by merging the bindings of all `shaders`.
+    pub fn new(shaders: &'a [Wgsl]) -> BindGroupLayout<'a> {
+        let mut groups: BTreeMap<u16, Vec<LayoutBinding<'a>>> = BTreeMap::new();
+
+        for shader in shaders {
+            for binding in &shader.bindings {
+                groups
+                    .entry(binding.attr_group())
+                    .or_default()
+                    .push(LayoutBinding {
+                        module: shader,
+                        binding,
+                        entry_points: entry_points_using(
+                            shaders,
+                            &shader.module_name,
+                            binding.name(),
+                        ),
+                    });
+            }
+        }
+
+        for bindings in groups.values_mut() {
+            bindings.sort_by(|a, b| {
+                a.binding
+                    .attr_binding()
+                    .cmp(&b.binding.attr_binding())
+                    .then_with(|| a.module.module_name.cmp(&b.module.module_name))
+            });
+        }
+
+        BindGroupLayout { groups }
+    }
+
+    /// Get field `groups` from instance of `BindGroupLayout`.
+    pub fn groups(&self) -> &BTreeMap<u16, Vec<LayoutBinding<'a>>> {
+        &self.groups
+    }
+
+    /// Renders the layout into a serializable form for templates.
+    pub fn rendered(&self) -> Vec<RenderedBindGroup> {
+        self.groups
+            .iter()
+            .map(|(group, bindings)| RenderedBindGroup {
+                group: *group,
+                bindings: bindings
+                    .iter()
+                    .map(|b| RenderedLayoutBinding {
+                        module: b.module.module_name.clone(),
+                        binding: b.binding.rendered(&b.module.imports),
+                        entry_points: b.entry_points.clone(),
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+/// A binding in the [`BindGroupLayout`] together with its module
+/// and the entry points using it.
+#[derive(Debug)]
+pub struct LayoutBinding<'a> {
+    module: &'a Wgsl,
+    binding: &'a Binding,
+    entry_points: Vec<EntryPointRef>,
+}
+
+impl<'a> LayoutBinding<'a> {
+    /// Get field `module` from instance of `LayoutBinding`.
+    pub fn module(&self) -> &'a Wgsl {
+        self.module
+    }
+
+    /// Get field `binding` from instance of `LayoutBinding`.
+    pub fn binding(&self) -> &'a Binding {
+        self.binding
+    }
+
+    /// Get field `entry_points` from instance of `LayoutBinding`.
+    pub fn entry_points(&self) -> &[EntryPointRef] {
+        &self.entry_points
+    }
+}
+
+/// Reference to an entry point function of a module.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EntryPointRef {
+    module: String,
+    name: String,
+    stage: ShaderStage,
+}
+
+impl EntryPointRef {
+    /// Get field `module` from instance of `EntryPointRef`.
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+
+    /// Get field `name` from instance of `EntryPointRef`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get field `stage` from instance of `EntryPointRef`.
+    pub fn stage(&self) -> ShaderStage {
+        self.stage
+    }
+}
+
+/// A serializable representation of a bind group for rendering purposes used in Tera.
+#[derive(Debug, Serialize)]
+pub struct RenderedBindGroup {
+    group: u16,
+    bindings: Vec<RenderedLayoutBinding>,
+}
+
+/// A serializable representation of a [`LayoutBinding`] for rendering purposes used in Tera.
+#[derive(Debug, Serialize)]
+pub struct RenderedLayoutBinding {
+    module: String,
+    #[serde(flatten)]
+    binding: RenderedBinding,
+    entry_points: Vec<EntryPointRef>,
+}
+
+/// Conflicting declarations of the same `@group`/`@binding` slot
+/// in modules that are used together by entry points.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindingConflict {
+    group: u16,
+    binding: u16,
+    entry_modules: Vec<String>,
+    declarations: Vec<SlotDeclaration>,
+}
+
+impl BindingConflict {
+    /// Get field `group` from instance of `BindingConflict`.
+    pub fn group(&self) -> u16 {
+        self.group
+    }
+
+    /// Get field `binding` from instance of `BindingConflict`.
+    pub fn binding(&self) -> u16 {
+        self.binding
+    }
+
+    /// Get field `entry_modules` from instance of `BindingConflict`.
+    /// These are the modules with entry points whose imports bring the declarations together.
+    pub fn entry_modules(&self) -> &[String] {
+        &self.entry_modules
+    }
+
+    /// Get field `declarations` from instance of `BindingConflict`.
+    pub fn declarations(&self) -> &[SlotDeclaration] {
+        &self.declarations
+    }
+}
+
+impl Display for BindingConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "@group({}) @binding({}) is declared with different types in modules used by `{}`: ",
+            self.group,
+            self.binding,
+            self.entry_modules.join("`, `"),
+        )?;
+
+        for (i, declaration) in self.declarations.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "`{declaration}`")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A binding declaration taking part in a [`BindingConflict`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlotDeclaration {
+    module: String,
+    name: String,
+    ty: String,
+}
+
+impl SlotDeclaration {
+    /// Get field `module` from instance of `SlotDeclaration`.
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+
+    /// Get field `name` from instance of `SlotDeclaration`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get field `ty` from instance of `SlotDeclaration`, written as WGSL
+    /// with user-defined types qualified by their declaring module.
+    pub fn declared_type(&self) -> &str {
+        &self.ty
+    }
+}
+
+impl Display for SlotDeclaration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}::{}: {}", self.module, self.name, self.ty)
+    }
+}
+
+/// Checks the bindings of all `shaders` for conflicting `@group`/`@binding` slots.
+///
+/// Starting from each module containing an entry point, the import graph is walked
+/// and the bindings of all reachable modules are collected. A slot declared by
+/// several of them with differing types is reported as a [`BindingConflict`].
+pub fn binding_conflicts(shaders: &[Wgsl]) -> Vec<BindingConflict> {
+    let mut conflicts: Vec<BindingConflict> = vec![];
+
+    for root in shaders
+        .iter()
+        .filter(|shader| shader.functions.iter().any(|f| f.is_entry_point()))
+    {
+        let mut slots: BTreeMap<(u16, u16), Vec<SlotDeclaration>> = BTreeMap::new();
+
+        for shader in imported_modules(shaders, root) {
+            for binding in &shader.bindings {
+                slots
+                    .entry((binding.attr_group(), binding.attr_binding()))
+                    .or_default()
+                    .push(SlotDeclaration {
+                        module: shader.module_name.clone(),
+                        name: binding.name().to_owned(),
+                        ty: type_signature(binding.binding_type(), shader),
+                    });
+            }
+        }
+
+        for ((group, binding), declarations) in slots {
+            if declarations.iter().all(|d| d.ty == declarations[0].ty) {
+                continue;
+            }
+
+            let existing = conflicts.iter_mut().find(|c| {
+                c.group == group && c.binding == binding && c.declarations == declarations
+            });
+
+            match existing {
+                Some(conflict) => conflict.entry_modules.push(root.module_name.clone()),
+                None => conflicts.push(BindingConflict {
+                    group,
+                    binding,
+                    entry_modules: vec![root.module_name.clone()],
+                    declarations,
+                }),
+            }
+        }
+    }
+
+    conflicts.sort_by_key(|c| (c.group, c.binding));
+    conflicts
+}
+
+/// Returns `root` and all the modules it imports, directly or transitively.
+fn imported_modules<'a>(shaders: &'a [Wgsl], root: &'a Wgsl) -> Vec<&'a Wgsl> {
+    let mut modules = vec![root];
+    let mut i = 0;
+
+    while i < modules.len() {
+        for import in &modules[i].imports {
+            let Some(imported) = shaders
+                .iter()
+                .find(|s| s.module_name == import.module_name())
+            else {
+                continue;
+            };
+
+            if !modules
+                .iter()
+                .any(|m| m.module_name == imported.module_name)
+            {
+                modules.push(imported);
+            }
+        }
+
+        i += 1;
+    }
+
+    modules
+}
+
+/// Writes `ty` as plain WGSL, qualifying user-defined types with
+/// the module declaring them, so that types can be compared across modules.
+fn type_signature(ty: &Type, shader: &Wgsl) -> String {
+    match ty {
+        Type::Primitive(primitive) => primitive.to_string(),
+        Type::Vector(vector) => vector.to_wgsl(),
+        Type::Matrix(matrix) => matrix.to_wgsl(),
+        Type::Texture(texture) => texture.to_wgsl(),
+        Type::Sampler(sampler) => sampler.to_string(),
+        Type::Atomic(primitive) => format!("atomic<{primitive}>"),
+        Type::Array(array) => match array.size() {
+            Some(size) => format!(
+                "array<{}, {size}>",
+                type_signature(array.element_type(), shader)
+            ),
+            None => format!("array<{}>", type_signature(array.element_type(), shader)),
+        },
+        Type::Pointer(pointer) => match pointer.access_mode() {
+            Some(access_mode) => format!(
+                "ptr<{}, {}, {access_mode}>",
+                pointer.address_space(),
+                type_signature(pointer.pointee_type(), shader)
+            ),
+            None => format!(
+                "ptr<{}, {}>",
+                pointer.address_space(),
+                type_signature(pointer.pointee_type(), shader)
+            ),
+        },
+        Type::Path(path) => {
+            let module = match path.import_module() {
+                ImportModule::Named(name) => shader
+                    .imports
+                    .iter()
+                    .find(|import| import.name() == name)
+                    .map(|import| import.module_name()),
+                ImportModule::This => Some(shader.module_name.as_str()),
+                ImportModule::Undefined => path.module(),
+            };
+
+            let name = match module {
+                Some(module) => format!("{module}::{}", path.name()),
+                None => path.name().to_owned(),
+            };
+
+            if path.generics().is_empty() {
+                name
+            } else {
+                let generics = path
+                    .generics()
+                    .iter()
+                    .map(|generic| type_signature(generic, shader))
+                    .collect::<Vec<_>>();
+
+                format!("{name}<{}>", generics.join(", "))
+            }
+        }
+    }
+}
+
+/// Returns the entry points of all `shaders` that reference item `name`
+/// of module `module_name`, either directly or through the functions they call.
+pub fn entry_points_using(shaders: &[Wgsl], module_name: &str, name: &str) -> Vec<EntryPointRef> {
+    let mut entry_points = vec![];
+
+    for shader in shaders {
+        for function in &shader.functions {
+            let Some(stage) = function.stage() else {
+                continue;
+            };
+
+            let mut visited = HashSet::new();
+            if references_item(shaders, shader, function, (module_name, name), &mut visited) {
+                entry_points.push(EntryPointRef {
+                    module: shader.module_name.clone(),
+                    name: function.name().to_owned(),
+                    stage,
+                });
+            }
+        }
+    }
+
+    entry_points
+}
+
+fn references_item<'a>(
+    shaders: &'a [Wgsl],
+    shader: &'a Wgsl,
+    function: &'a Function,
+    item: (&str, &str),
+    visited: &mut HashSet<(&'a str, &'a str)>,
+) -> bool {
+    for reference in function.references() {
+        let target = resolve_reference(shaders, shader, reference);
+
+        if target == item {
+            return true;
+        }
+
+        if !visited.insert(target) {
+            continue;
+        }
+
+        let (module_name, name) = target;
+        let Some(callee_shader) = shaders.iter().find(|s| s.module_name == module_name) else {
+            continue;
+        };
+
+        if let Some(callee) = callee_shader.functions.iter().find(|f| f.name() == name) {
+            if references_item(shaders, callee_shader, callee, item, visited) {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+/// Resolves a (possibly module-qualified) reference from `shader` into a pair
+/// of module name and item name, following the module's imports.
+/// The module of a qualified reference is either an imported module, by its name or its path,
+/// or any module of `shaders` by its import path (e.g. `bevy_pbr::mesh_view_bindings::view`).
+fn resolve_reference<'a>(
+    shaders: &'a [Wgsl],
+    shader: &'a Wgsl,
+    reference: &'a str,
+) -> (&'a str, &'a str) {
+    match reference.rsplit_once("::") {
+        Some((module, name)) => {
+            let module = shader
+                .imports
+                .iter()
+                .find(|import| import.name() == module || import.module_path() == Some(module))
+                .map(|import| import.module_name())
+                .or_else(|| {
+                    shaders
+                        .iter()
+                        .find(|s| {
+                            s.module_name == module || s.import_path.as_deref() == Some(module)
+                        })
+                        .map(|s| s.module_name.as_str())
+                })
+                .unwrap_or(module);
+
+            (module, name)
+        }
+        None => {
+            let module = shader
+                .imports
+                .iter()
+                .find(|import| import.item(reference).is_some())
+                .map_or(shader.module_name.as_str(), |import| import.module_name());
+
+            (module, reference)
+        }
+    }
+}
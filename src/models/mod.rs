@@ -1,26 +1,48 @@
 //! Models module containing data structures for representing WGSL components.
 //! This components are used throughout the application for parsing, processing, and generating documentation.
 //! This includes:
+//! - Aliases
 //! - Bindings
-//! - Constants
+//! - Conditions (preprocessor)
+//! - Constants and overrides
+//! - Directives
 //! - Functions
+//! - Global variables
 //! - Imports
+//! - Layout (bind groups)
+//! - Skipped items (parsing diagnostics)
 //! - Structures
 //! - Types
 
 use crate::{
-    models::{binding::Binding, constant::Constant, types::RenderedType},
+    models::{
+        alias::Alias,
+        binding::Binding,
+        condition::Condition,
+        constant::{Constant, Override},
+        directive::{Diagnostic, Directive},
+        global_var::GlobalVar,
+        skipped_item::SkippedItem,
+        types::RenderedType,
+    },
     utils::html::to_html,
 };
 use function::Function;
-use import::Import;
+use import::{Import, ItemKind, ModuleItems};
 use serde::Serialize;
+use std::collections::HashMap;
 use structure::Structure;
 
+pub mod alias;
 pub mod binding;
+pub mod condition;
 pub mod constant;
+pub mod directive;
 pub mod function;
+pub mod global_var;
 pub mod import;
+pub mod layout;
+pub mod skipped_item;
 pub mod structure;
 pub mod types;
 
@@ -42,30 +64,144 @@ impl ComponentInfo {
     pub fn new(name: String, summary: Option<String>) -> Self {
         ComponentInfo { name, summary }
     }
+
+    /// Truncates `summary` to `SUMMARY_MAX_LENGTH` characters, appending `...` if it's longer.
+    pub fn truncate_summary(summary: String) -> String {
+        match summary.char_indices().nth(Self::SUMMARY_MAX_LENGTH) {
+            Some((index, _)) => format!("{}...", &summary[..index]),
+            None => summary,
+        }
+    }
 }
 
 /// Main WGSL model representing a shader module with all its components.
 #[derive(Debug)]
 pub struct Wgsl {
-    /// The name of the module.
+    /// The name of the module. Either the name of the shader file
+    /// or the import path declared with `#define_import_path`.
     pub module_name: String,
+    /// The import path declared with `#define_import_path`, if any.
+    pub import_path: Option<String>,
     /// The original WGSL source code.
     pub source_code: String,
     /// The global documentation comments for the module.
     pub global_docs: Option<String>,
+    /// The list of global directives (`enable`, `requires`, `diagnostic`) in the module.
+    pub directives: Vec<Directive>,
     /// The list of imports for the module.
     pub imports: Vec<Import>,
     /// The list of functions in the module.
     pub functions: Vec<Function>,
     /// The list of structures in the module.
     pub structures: Vec<Structure>,
+    /// The list of type aliases in the module.
+    pub aliases: Vec<Alias>,
     /// The list of constants in the module.
     pub constants: Vec<Constant>,
+    /// The list of pipeline-overridable constants in the module.
+    pub overrides: Vec<Override>,
     /// The list of bindings in the module.
     pub bindings: Vec<Binding>,
+    /// The list of module-scope variables without bindings (e.g. `var<workgroup>`) in the module.
+    pub global_vars: Vec<GlobalVar>,
+    /// The preprocessor conditions of the items declared inside conditional blocks
+    /// (`#ifdef`, `#ifndef`, `#if`), by item kind and name. An item declared in several branches
+    /// has several alternative sets of conditions, any of which makes it available.
+    pub conditions: HashMap<(ItemKind, String), Vec<Vec<Condition>>>,
+    /// The top-level items skipped because they couldn't be parsed.
+    /// Only filled when the module is parsed in recovery mode.
+    pub skipped_items: Vec<SkippedItem>,
 }
 
 impl Wgsl {
+    /// Returns the names of all extensions enabled by `enable` directives.
+    pub fn extensions(&self) -> Vec<&str> {
+        self.directives
+            .iter()
+            .filter_map(|d| match d {
+                Directive::Enable(names) => Some(names),
+                _ => None,
+            })
+            .flatten()
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Returns the names of all language features required by `requires` directives.
+    pub fn language_features(&self) -> Vec<&str> {
+        self.directives
+            .iter()
+            .filter_map(|d| match d {
+                Directive::Requires(names) => Some(names),
+                _ => None,
+            })
+            .flatten()
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Returns all global `diagnostic` directives.
+    pub fn diagnostics(&self) -> Vec<&Diagnostic> {
+        self.directives
+            .iter()
+            .filter_map(|d| match d {
+                Directive::Diagnostic(diagnostic) => Some(diagnostic),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the alternative sets of preprocessor conditions the item of `kind` named `name` depends on.
+    /// Empty if the item is declared outside of any conditional block, or in every branch of it.
+    pub fn conditions_of(&self, kind: ItemKind, name: &str) -> &[Vec<Condition>] {
+        self.conditions
+            .get(&(kind, name.to_owned()))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the names and kinds of all items declared in the module.
+    pub fn module_items(&self) -> ModuleItems {
+        let functions = self
+            .functions
+            .iter()
+            .map(|f| (f.name().to_owned(), ItemKind::Function));
+        let structures = self
+            .structures
+            .iter()
+            .map(|s| (s.name().to_owned(), ItemKind::Struct));
+        let aliases = self
+            .aliases
+            .iter()
+            .map(|a| (a.name().to_owned(), ItemKind::Alias));
+        let constants = self
+            .constants
+            .iter()
+            .map(|c| (c.name().to_owned(), ItemKind::Constant));
+        let overrides = self
+            .overrides
+            .iter()
+            .map(|o| (o.name().to_owned(), ItemKind::Override));
+        let bindings = self
+            .bindings
+            .iter()
+            .map(|b| (b.name().to_owned(), ItemKind::Binding));
+        let global_vars = self
+            .global_vars
+            .iter()
+            .map(|v| (v.name().to_owned(), ItemKind::GlobalVar));
+
+        let items = functions
+            .chain(structures)
+            .chain(aliases)
+            .chain(constants)
+            .chain(overrides)
+            .chain(bindings)
+            .chain(global_vars)
+            .collect();
+
+        ModuleItems::new(self.module_name.clone(), items)
+    }
+
     /// Returns a [`ComponentInfo`] containing a summary of the WGSL documentation,
     /// with the summary extracted from the rendered Markdown as HTML.
     pub fn info_rich_text(&self) -> ComponentInfo {
@@ -90,11 +226,7 @@ impl Wgsl {
                 .trim()
                 .to_string();
 
-            if summary.len() > ComponentInfo::SUMMARY_MAX_LENGTH {
-                format!("{}...", &summary[..ComponentInfo::SUMMARY_MAX_LENGTH])
-            } else {
-                summary
-            }
+            ComponentInfo::truncate_summary(summary)
         });
 
         ComponentInfo::new(self.module_name.clone(), summary)
@@ -108,4 +240,5 @@ pub struct RenderedArgField {
     docs: Option<String>,
     name: String,
     ty: RenderedType,
+    attributes: Vec<String>,
 }
@@ -0,0 +1,46 @@
+//! Skipped item model module used for representing top-level items which couldn't be parsed.
+//! Used as diagnostics of shaders parsed in recovery mode.
+
+use std::fmt::Display;
+
+/// Represents a top-level item skipped by the parser in recovery mode, because it couldn't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedItem {
+    line: usize,
+    source: String,
+    message: String,
+}
+
+impl SkippedItem {
+    /// Creates a new SkippedItem instance (usually from parsed elements).
+    pub fn new(line: usize, source: String, message: String) -> SkippedItem {
+        SkippedItem {
+            line,
+            source,
+            message,
+        }
+    }
+
+    /// Get field `line` from instance of `SkippedItem`.
+    /// The line number (1-based) the skipped item starts at.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Get field `source` from instance of `SkippedItem`.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Get field `message` from instance of `SkippedItem`.
+    /// Describes why the item couldn't be parsed.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for SkippedItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
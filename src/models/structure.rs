@@ -3,11 +3,12 @@
 
 use super::{
     import::{Import, RegisterImports},
-    types::Type,
+    types::{Type, TypeKind},
 };
+use std::fmt::Display;
 use crate::{
     impl_eq_name,
-    models::{types::RenderedType, ComponentInfo, RenderedArgField},
+    models::{ComponentInfo, RenderedArgField},
     utils::html::to_html,
 };
 
@@ -35,24 +36,11 @@ impl Structure {
     pub fn rendered_fields(&self, imports: &[Import]) -> Vec<RenderedArgField> {
         self.fields()
             .iter()
-            .map(|field| {
-                let ty = match field.field_type() {
-                    Type::Primitive(p) => RenderedType {
-                        name: p.to_string(),
-                        ..Default::default()
-                    },
-                    Type::Vector(v) => RenderedType {
-                        name: v.to_string(),
-                        ..Default::default()
-                    },
-                    Type::Path(path) => Type::Path(path.clone()).rendered_type(imports, false),
-                };
-
-                RenderedArgField {
-                    docs: field.docs().map(to_html),
-                    name: field.name().to_string(),
-                    ty,
-                }
+            .map(|field| RenderedArgField {
+                docs: field.docs().map(to_html),
+                name: field.name().to_string(),
+                ty: field.field_type().rendered_type(imports),
+                attributes: field.attributes().iter().map(|a| a.to_string()).collect(),
             })
             .collect()
     }
@@ -81,11 +69,7 @@ impl Structure {
                 .trim()
                 .to_string();
 
-            if summary.len() > ComponentInfo::SUMMARY_MAX_LENGTH {
-                format!("{}...", &summary[..ComponentInfo::SUMMARY_MAX_LENGTH])
-            } else {
-                summary
-            }
+            ComponentInfo::truncate_summary(summary)
         });
 
         ComponentInfo::new(self.name.clone(), summary)
@@ -114,9 +98,9 @@ impl RegisterImports for Structure {
         }
     }
 
-    fn register_same_module_types(&mut self, type_names: &[String]) {
+    fn register_same_module_types(&mut self, types: &[(String, TypeKind)]) {
         for field in &mut self.fields {
-            field.register_same_module_types(type_names);
+            field.register_same_module_types(types);
         }
     }
 }
@@ -133,14 +117,46 @@ impl_eq_name!(Structure::name);
 #[derive(Debug)]
 pub struct Field {
     docs: Option<String>,
+    attributes: Vec<MemberAttribute>,
     name: String,
     ty: Type,
 }
 
 impl Field {
     /// Creates a new Field instance (usually from parsed elements).
-    pub fn new(docs: Option<String>, name: String, ty: Type) -> Field {
-        Field { docs, name, ty }
+    pub fn new(
+        docs: Option<String>,
+        attributes: Vec<MemberAttribute>,
+        name: String,
+        ty: Type,
+    ) -> Field {
+        Field {
+            docs,
+            attributes,
+            name,
+            ty,
+        }
+    }
+
+    /// Get field `attributes` from instance of `Field`.
+    pub fn attributes(&self) -> &[MemberAttribute] {
+        &self.attributes
+    }
+
+    /// Returns the IO location of the field, if it has a `@location` attribute.
+    pub fn location(&self) -> Option<u32> {
+        self.attributes.iter().find_map(|attr| match attr {
+            MemberAttribute::Location(location) => Some(*location),
+            _ => None,
+        })
+    }
+
+    /// Returns the builtin value of the field, if it has a `@builtin` attribute.
+    pub fn builtin(&self) -> Option<&str> {
+        self.attributes.iter().find_map(|attr| match attr {
+            MemberAttribute::Builtin(builtin) => Some(builtin.as_str()),
+            _ => None,
+        })
     }
 
     /// Get field `docs` from instance of `Field`.
@@ -161,16 +177,55 @@ impl Field {
 
 impl RegisterImports for Field {
     fn register_imports(&mut self, imports: &[Import]) {
-        if let Type::Path(ty) = &mut self.ty {
-            ty.register_imports(imports);
-        }
+        self.ty.register_imports(imports);
     }
 
-    fn register_same_module_types(&mut self, type_names: &[String]) {
-        if let Type::Path(ty) = &mut self.ty {
-            ty.register_same_module_types(type_names);
-        }
+    fn register_same_module_types(&mut self, types: &[(String, TypeKind)]) {
+        self.ty.register_same_module_types(types);
     }
 }
 
 impl_eq_name!(Field::name);
+
+/// Attribute of a structure member, describing either its memory layout
+/// or its role in the shader stage interface. Example:
+/// ```wgsl
+/// @location(0) @interpolate(flat) id: u32,
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MemberAttribute {
+    /// Byte alignment of the member (`@align(16)`).
+    Align(u32),
+    /// Byte size of the member (`@size(8)`).
+    Size(u32),
+    /// IO location of the member (`@location(0)`).
+    Location(u32),
+    /// Builtin value of the member (`@builtin(position)`).
+    Builtin(String),
+    /// Interpolation of a user-defined IO member (`@interpolate(perspective, centroid)`).
+    Interpolate {
+        /// Interpolation type: `perspective`, `linear` or `flat`.
+        ty: String,
+        /// Interpolation sampling, e.g. `center`, `centroid` or `sample`.
+        sampling: Option<String>,
+    },
+    /// Invariance of a `position` builtin (`@invariant`).
+    Invariant,
+}
+
+impl Display for MemberAttribute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MemberAttribute::Align(align) => write!(f, "@align({align})"),
+            MemberAttribute::Size(size) => write!(f, "@size({size})"),
+            MemberAttribute::Location(location) => write!(f, "@location({location})"),
+            MemberAttribute::Builtin(builtin) => write!(f, "@builtin({builtin})"),
+            MemberAttribute::Interpolate { ty, sampling: None } => write!(f, "@interpolate({ty})"),
+            MemberAttribute::Interpolate {
+                ty,
+                sampling: Some(sampling),
+            } => write!(f, "@interpolate({ty}, {sampling})"),
+            MemberAttribute::Invariant => write!(f, "@invariant"),
+        }
+    }
+}
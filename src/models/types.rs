@@ -6,36 +6,83 @@ use super::import::{Import, RegisterImports};
 use serde::Serialize;
 use std::fmt::Display;
 
-/// Represents a type in WGSL. Can be a primitive, vector, or path type.
+/// Represents a type in WGSL. Can be a primitive, vector, matrix, array, texture,
+/// sampler, atomic, pointer, or path type.
 #[derive(Debug)]
 pub enum Type {
     /// A primitive type (e.g., `f32`, `i32`).
     Primitive(Primitive),
     /// A vector type (e.g., `vec2<T>`, `vec3<T>`, `vec4<T>`).
     Vector(Vector),
+    /// A matrix type (e.g., `mat4x4<T>`, `mat3x2<T>`).
+    Matrix(Matrix),
+    /// An array type (e.g., `array<T, N>`, `array<T>`).
+    Array(Array),
+    /// A texture type (e.g., `texture_2d<T>`, `texture_storage_2d<F, A>`).
+    Texture(Texture),
+    /// A sampler type (`sampler` or `sampler_comparison`).
+    Sampler(Sampler),
+    /// An atomic type (e.g., `atomic<u32>`).
+    Atomic(Primitive),
+    /// A pointer type (e.g., `ptr<function, T>`, `ptr<storage, T, read_write>`).
+    Pointer(Pointer),
     /// A path type (e.g., `MyType`, `Module::MyType`).
     Path(PathType),
 }
 
 impl Type {
     /// Converts the [`Type`] into a [`RenderedType`] for documentation rendering.
-    pub fn rendered_type(&self, imports: &[Import], is_fn_ptr: bool) -> RenderedType {
+    pub fn rendered_type(&self, imports: &[Import]) -> RenderedType {
         match self {
             Type::Primitive(p) => RenderedType {
                 name: p.to_string(),
-                is_function_pointer: is_fn_ptr,
+                extension: self.extension().map(str::to_owned),
+                is_non_standard: !self.is_standard(),
                 ..Default::default()
             },
             Type::Vector(v) => RenderedType {
                 name: v.to_string(),
-                is_function_pointer: is_fn_ptr,
+                extension: self.extension().map(str::to_owned),
+                is_non_standard: !self.is_standard(),
                 ..Default::default()
             },
+            Type::Matrix(m) => RenderedType {
+                name: m.to_string(),
+                extension: self.extension().map(str::to_owned),
+                is_non_standard: !self.is_standard(),
+                ..Default::default()
+            },
+            Type::Texture(texture) => RenderedType {
+                name: texture.to_string(),
+                extension: self.extension().map(str::to_owned),
+                is_non_standard: !self.is_standard(),
+                ..Default::default()
+            },
+            Type::Sampler(sampler) => RenderedType {
+                name: sampler.to_string(),
+                ..Default::default()
+            },
+            Type::Array(array) => array.rendered_type(imports),
+            Type::Atomic(p) => RenderedType {
+                name: "atomic".to_string(),
+                element: Some(Box::new(RenderedType {
+                    name: p.to_string(),
+                    extension: p.extension().map(str::to_owned),
+                    is_non_standard: !p.is_standard(),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            },
+            Type::Pointer(pointer) => pointer.rendered_type(imports),
             Type::Path(path) => {
                 let mut rty = RenderedType {
                     name: path.name().to_string(),
                     module: path.module().map(|s| s.to_string()),
-                    is_function_pointer: is_fn_ptr,
+                    generics: path
+                        .generics()
+                        .iter()
+                        .map(|generic| generic.rendered_type(imports))
+                        .collect(),
                     ..Default::default()
                 };
 
@@ -45,9 +92,11 @@ impl Type {
                             .iter()
                             .find(|i| i.name() == name)
                             .map(|i| i.module_name().to_string());
+                        rty.kind = path.kind();
                     }
                     ImportModule::This => {
                         rty.is_this = true;
+                        rty.kind = path.kind();
                     }
                     _ => {}
                 }
@@ -56,6 +105,48 @@ impl Type {
             }
         }
     }
+
+    /// Returns `true` if the type only uses core WGSL primitives, i.e. it doesn't
+    /// depend on an extension (like `f16`) or a non-standard primitive type.
+    /// User-defined path types are considered core, but their generic arguments are checked.
+    pub fn is_core(&self) -> bool {
+        self.all_primitives(Primitive::is_core)
+    }
+
+    /// Returns `true` if the type only uses primitives which are part of WGSL,
+    /// either core or enabled by an extension (unlike e.g. `i8` or `f64`).
+    pub fn is_standard(&self) -> bool {
+        self.all_primitives(Primitive::is_standard)
+    }
+
+    /// Returns the name of the WGSL extension which must be enabled to use the type
+    /// (e.g. `enable f16;`), if it uses a primitive from one.
+    pub fn extension(&self) -> Option<&'static str> {
+        match self {
+            Type::Primitive(p) | Type::Atomic(p) => p.extension(),
+            Type::Vector(v) => v.vector_type().extension(),
+            Type::Matrix(m) => m.matrix_type().extension(),
+            Type::Texture(texture) => texture.sample_type().and_then(Primitive::extension),
+            Type::Sampler(_) => None,
+            Type::Array(array) => array.element_type().extension(),
+            Type::Pointer(pointer) => pointer.pointee_type().extension(),
+            Type::Path(path) => path.generics().iter().find_map(Type::extension),
+        }
+    }
+
+    /// Returns `true` if `predicate` holds for all primitives used by the type.
+    fn all_primitives(&self, predicate: fn(&Primitive) -> bool) -> bool {
+        match self {
+            Type::Primitive(p) | Type::Atomic(p) => predicate(p),
+            Type::Vector(v) => predicate(v.vector_type()),
+            Type::Matrix(m) => predicate(m.matrix_type()),
+            Type::Texture(texture) => texture.sample_type().is_none_or(predicate),
+            Type::Sampler(_) => true,
+            Type::Array(array) => array.element_type().all_primitives(predicate),
+            Type::Pointer(pointer) => pointer.pointee_type().all_primitives(predicate),
+            Type::Path(path) => path.generics().iter().all(|g| g.all_primitives(predicate)),
+        }
+    }
 }
 
 impl Default for Type {
@@ -64,11 +155,33 @@ impl Default for Type {
     }
 }
 
+impl RegisterImports for Type {
+    fn register_imports(&mut self, imports: &[Import]) {
+        match self {
+            Type::Array(array) => array.register_imports(imports),
+            Type::Pointer(pointer) => pointer.register_imports(imports),
+            Type::Path(path) => path.register_imports(imports),
+            _ => {}
+        }
+    }
+
+    fn register_same_module_types(&mut self, types: &[(String, TypeKind)]) {
+        match self {
+            Type::Array(array) => array.register_same_module_types(types),
+            Type::Pointer(pointer) => pointer.register_same_module_types(types),
+            Type::Path(path) => path.register_same_module_types(types),
+            _ => {}
+        }
+    }
+}
+
 /// Represents primitive WGSL types.
 #[derive(Debug, Default)]
 pub enum Primitive {
     /// A boolean type.
     Bool,
+    /// A 16-bit floating-point type.
+    Float16,
     /// A 32-bit floating-point type.
     Float32,
     /// A 64-bit floating-point type.
@@ -92,10 +205,39 @@ pub enum Primitive {
     Sint64,
 }
 
+impl Primitive {
+    /// Returns `true` if the primitive is part of core WGSL
+    /// (`bool`, `f32`, `i32` and `u32`).
+    pub fn is_core(&self) -> bool {
+        matches!(
+            self,
+            Primitive::Bool | Primitive::Float32 | Primitive::Sint32 | Primitive::Uint32
+        )
+    }
+
+    /// Returns `true` if the primitive is part of WGSL, either core
+    /// or enabled by an extension (`f16`). Non-standard primitives (like `i8` or `f64`)
+    /// are only supported by some implementations.
+    pub fn is_standard(&self) -> bool {
+        self.is_core() || self.extension().is_some()
+    }
+
+    /// Returns the name of the WGSL extension which must be enabled to use the primitive
+    /// (e.g. `enable f16;`), if there is one. Core primitives and non-standard
+    /// primitives (like `i8` or `f64`) have no WGSL extension.
+    pub fn extension(&self) -> Option<&'static str> {
+        match self {
+            Primitive::Float16 => Some("f16"),
+            _ => None,
+        }
+    }
+}
+
 impl Display for Primitive {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Primitive::Bool => write!(f, "bool"),
+            Primitive::Float16 => write!(f, "f16"),
             Primitive::Float32 => write!(f, "f32"),
             Primitive::Float64 => write!(f, "f64"),
             Primitive::Uint8 => write!(f, "u8"),
@@ -133,6 +275,12 @@ impl Vector {
         Vector { dimension, ty }
     }
 
+    /// Writes the vector type as plain WGSL, e.g. `vec3<f32>`.
+    /// Unlike [`Display`], `<` and `>` aren't escaped for HTML.
+    pub fn to_wgsl(&self) -> String {
+        format!("vec{}<{}>", self.dimension, self.ty)
+    }
+
     /// Get field `type` from instance of `Vector`.
     pub fn vector_type(&self) -> &Primitive {
         &self.ty
@@ -156,6 +304,407 @@ pub enum VectorDimension {
     D4,
 }
 
+impl Display for VectorDimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VectorDimension::D2 => write!(f, "2"),
+            VectorDimension::D3 => write!(f, "3"),
+            VectorDimension::D4 => write!(f, "4"),
+        }
+    }
+}
+
+/// Represents matrix WGSL types with a number of columns and rows.
+#[derive(Debug, Default)]
+pub struct Matrix {
+    columns: VectorDimension,
+    rows: VectorDimension,
+    ty: Primitive,
+}
+
+impl Display for Matrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "mat{}x{}&lt;{}&gt;", self.columns, self.rows, self.ty)
+    }
+}
+
+impl Matrix {
+    /// Creates a new Matrix instance (usually from parsed elements).
+    pub fn new(columns: VectorDimension, rows: VectorDimension, ty: Primitive) -> Matrix {
+        Matrix { columns, rows, ty }
+    }
+
+    /// Writes the matrix type as plain WGSL, e.g. `mat4x4<f32>`.
+    /// Unlike [`Display`], `<` and `>` aren't escaped for HTML.
+    pub fn to_wgsl(&self) -> String {
+        format!("mat{}x{}<{}>", self.columns, self.rows, self.ty)
+    }
+
+    /// Get field `columns` from instance of `Matrix`.
+    pub fn columns(&self) -> &VectorDimension {
+        &self.columns
+    }
+
+    /// Get field `rows` from instance of `Matrix`.
+    pub fn rows(&self) -> &VectorDimension {
+        &self.rows
+    }
+
+    /// Get field `type` from instance of `Matrix`.
+    pub fn matrix_type(&self) -> &Primitive {
+        &self.ty
+    }
+}
+
+/// Dimensionality of a texture.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum TextureDimension {
+    /// One-dimensional texture (`1d`).
+    D1,
+    /// Two-dimensional texture (`2d`).
+    #[default]
+    D2,
+    /// Array of two-dimensional textures (`2d_array`).
+    D2Array,
+    /// Three-dimensional texture (`3d`).
+    D3,
+    /// Cube texture (`cube`).
+    Cube,
+    /// Array of cube textures (`cube_array`).
+    CubeArray,
+}
+
+impl Display for TextureDimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TextureDimension::D1 => write!(f, "1d"),
+            TextureDimension::D2 => write!(f, "2d"),
+            TextureDimension::D2Array => write!(f, "2d_array"),
+            TextureDimension::D3 => write!(f, "3d"),
+            TextureDimension::Cube => write!(f, "cube"),
+            TextureDimension::CubeArray => write!(f, "cube_array"),
+        }
+    }
+}
+
+/// Kind of a texture, deciding which template arguments it takes.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum TextureKind {
+    /// Sampled texture (e.g., `texture_2d<f32>`).
+    #[default]
+    Sampled,
+    /// Multisampled texture (`texture_multisampled_2d<T>`).
+    Multisampled,
+    /// Depth texture (e.g., `texture_depth_2d`).
+    Depth,
+    /// Multisampled depth texture (`texture_depth_multisampled_2d`).
+    DepthMultisampled,
+    /// Storage texture (e.g., `texture_storage_2d<rgba8unorm, write>`).
+    Storage,
+    /// External texture (`texture_external`).
+    External,
+}
+
+/// Access mode of a storage texture, buffer or pointer.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccessMode {
+    /// Read-only access.
+    #[default]
+    Read,
+    /// Write-only access.
+    Write,
+    /// Read and write access.
+    ReadWrite,
+}
+
+impl Display for AccessMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccessMode::Read => write!(f, "read"),
+            AccessMode::Write => write!(f, "write"),
+            AccessMode::ReadWrite => write!(f, "read_write"),
+        }
+    }
+}
+
+/// Represents texture WGSL types with their dimension, sample type,
+/// texel format and access mode.
+#[derive(Debug, Default)]
+pub struct Texture {
+    kind: TextureKind,
+    dimension: TextureDimension,
+    sample_type: Option<Primitive>,
+    texel_format: Option<String>,
+    access_mode: Option<AccessMode>,
+}
+
+impl Display for Texture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.write_with_brackets(f, "&lt;", "&gt;")
+    }
+}
+
+impl Texture {
+    /// Writes the texture type, enclosing its template arguments in `open` and `close`.
+    fn write_with_brackets(
+        &self,
+        f: &mut impl std::fmt::Write,
+        open: &str,
+        close: &str,
+    ) -> std::fmt::Result {
+        match self.kind {
+            TextureKind::Sampled => write!(f, "texture_{}", self.dimension)?,
+            TextureKind::Multisampled => write!(f, "texture_multisampled_{}", self.dimension)?,
+            TextureKind::Depth => write!(f, "texture_depth_{}", self.dimension)?,
+            TextureKind::DepthMultisampled => {
+                write!(f, "texture_depth_multisampled_{}", self.dimension)?
+            }
+            TextureKind::Storage => write!(f, "texture_storage_{}", self.dimension)?,
+            TextureKind::External => write!(f, "texture_external")?,
+        }
+
+        if let Some(sample_type) = &self.sample_type {
+            write!(f, "{open}{}{close}", sample_type)?;
+        } else if let (Some(format), Some(access)) = (&self.texel_format, &self.access_mode) {
+            write!(f, "{open}{}, {}{close}", format, access)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Texture {
+    /// Creates a new sampled or multisampled Texture instance.
+    pub fn sampled(
+        kind: TextureKind,
+        dimension: TextureDimension,
+        sample_type: Primitive,
+    ) -> Texture {
+        Texture {
+            kind,
+            dimension,
+            sample_type: Some(sample_type),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new storage Texture instance.
+    pub fn storage(
+        dimension: TextureDimension,
+        texel_format: String,
+        access_mode: AccessMode,
+    ) -> Texture {
+        Texture {
+            kind: TextureKind::Storage,
+            dimension,
+            texel_format: Some(texel_format),
+            access_mode: Some(access_mode),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new Texture instance without template arguments
+    /// (depth and external textures).
+    pub fn new(kind: TextureKind, dimension: TextureDimension) -> Texture {
+        Texture {
+            kind,
+            dimension,
+            ..Default::default()
+        }
+    }
+
+    /// Writes the texture type as plain WGSL, e.g. `texture_storage_2d<rgba8unorm, write>`.
+    /// Unlike [`Display`], `<` and `>` aren't escaped for HTML.
+    pub fn to_wgsl(&self) -> String {
+        let mut wgsl = String::new();
+        let _ = self.write_with_brackets(&mut wgsl, "<", ">");
+        wgsl
+    }
+
+    /// Get field `kind` from instance of `Texture`.
+    pub fn kind(&self) -> TextureKind {
+        self.kind
+    }
+
+    /// Get field `dimension` from instance of `Texture`.
+    pub fn dimension(&self) -> TextureDimension {
+        self.dimension
+    }
+
+    /// Get field `sample_type` from instance of `Texture`.
+    pub fn sample_type(&self) -> Option<&Primitive> {
+        self.sample_type.as_ref()
+    }
+
+    /// Get field `texel_format` from instance of `Texture`.
+    pub fn texel_format(&self) -> Option<&str> {
+        self.texel_format.as_deref()
+    }
+
+    /// Get field `access_mode` from instance of `Texture`.
+    pub fn access_mode(&self) -> Option<AccessMode> {
+        self.access_mode
+    }
+}
+
+/// Represents sampler WGSL types.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum Sampler {
+    /// Filtering or non-filtering sampler (`sampler`).
+    #[default]
+    Sampler,
+    /// Comparison sampler (`sampler_comparison`).
+    Comparison,
+}
+
+impl Display for Sampler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Sampler::Sampler => write!(f, "sampler"),
+            Sampler::Comparison => write!(f, "sampler_comparison"),
+        }
+    }
+}
+
+/// Represents array WGSL types, either fixed-size or runtime-sized.
+#[derive(Debug)]
+pub struct Array {
+    ty: Box<Type>,
+    size: Option<String>,
+}
+
+impl Array {
+    /// Creates a new Array instance (usually from parsed elements).
+    /// Arrays without `size` are runtime-sized.
+    pub fn new(ty: Type, size: Option<String>) -> Array {
+        Array {
+            ty: Box::new(ty),
+            size,
+        }
+    }
+
+    /// Get field `type` (the element type) from instance of `Array`.
+    pub fn element_type(&self) -> &Type {
+        &self.ty
+    }
+
+    /// Get field `size` from instance of `Array`.
+    /// The size is an expression, which may refer to a const or an override.
+    pub fn size(&self) -> Option<&str> {
+        self.size.as_deref()
+    }
+
+    /// Returns `true` if the array has no fixed size.
+    pub fn is_runtime_sized(&self) -> bool {
+        self.size.is_none()
+    }
+
+    /// Converts the [`Array`] into a [`RenderedType`] for documentation rendering.
+    pub fn rendered_type(&self, imports: &[Import]) -> RenderedType {
+        RenderedType {
+            name: "array".to_string(),
+            element: Some(Box::new(self.ty.rendered_type(imports))),
+            size: self.size.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+impl RegisterImports for Array {
+    fn register_imports(&mut self, imports: &[Import]) {
+        self.ty.register_imports(imports);
+    }
+
+    fn register_same_module_types(&mut self, types: &[(String, TypeKind)]) {
+        self.ty.register_same_module_types(types);
+    }
+}
+
+/// Represents WGSL pointer types with their address space, pointee type and access mode.
+#[derive(Debug)]
+pub struct Pointer {
+    address_space: AddressSpace,
+    ty: Box<Type>,
+    access_mode: Option<AccessMode>,
+}
+
+impl Pointer {
+    /// Creates a new Pointer instance (usually from parsed elements).
+    pub fn new(address_space: AddressSpace, ty: Type, access_mode: Option<AccessMode>) -> Pointer {
+        Pointer {
+            address_space,
+            ty: Box::new(ty),
+            access_mode,
+        }
+    }
+
+    /// Get field `address_space` from instance of `Pointer`.
+    pub fn address_space(&self) -> AddressSpace {
+        self.address_space
+    }
+
+    /// Get field `ty` from instance of `Pointer`.
+    pub fn pointee_type(&self) -> &Type {
+        &self.ty
+    }
+
+    /// Get field `access_mode` from instance of `Pointer`.
+    pub fn access_mode(&self) -> Option<AccessMode> {
+        self.access_mode
+    }
+
+    /// Converts the [`Pointer`] into a [`RenderedType`] for documentation rendering.
+    pub fn rendered_type(&self, imports: &[Import]) -> RenderedType {
+        RenderedType {
+            name: "ptr".to_string(),
+            element: Some(Box::new(self.ty.rendered_type(imports))),
+            address_space: Some(self.address_space),
+            access_mode: self.access_mode,
+            ..Default::default()
+        }
+    }
+}
+
+impl RegisterImports for Pointer {
+    fn register_imports(&mut self, imports: &[Import]) {
+        self.ty.register_imports(imports);
+    }
+
+    fn register_same_module_types(&mut self, types: &[(String, TypeKind)]) {
+        self.ty.register_same_module_types(types);
+    }
+}
+
+/// Represents the address space (storage class) of a variable or pointer.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AddressSpace {
+    /// Private to the invocation, visible only in the declaring function.
+    Function,
+    /// Private to the invocation, visible in the whole module.
+    #[default]
+    Private,
+    /// Shared between invocations of a compute workgroup.
+    Workgroup,
+    /// Uniform buffer memory.
+    Uniform,
+    /// Storage buffer memory.
+    Storage,
+}
+
+impl Display for AddressSpace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AddressSpace::Function => write!(f, "function"),
+            AddressSpace::Private => write!(f, "private"),
+            AddressSpace::Workgroup => write!(f, "workgroup"),
+            AddressSpace::Uniform => write!(f, "uniform"),
+            AddressSpace::Storage => write!(f, "storage"),
+        }
+    }
+}
+
 /// Structure inside a path type, indicating its import status, whether
 /// it's imported from another module, defined in the same module or 
 /// its origin is undefined.
@@ -170,21 +719,37 @@ pub enum ImportModule {
     This,
 }
 
-/// Represents a path type in WGSL, which may include module information and import status.
-#[derive(Debug, Clone)]
+/// Kind of a user-defined type, deciding which documentation page a [`PathType`] links to.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TypeKind {
+    /// A structure, documented on a `struct.<Name>.html` page.
+    #[default]
+    Struct,
+    /// A type alias, documented on an `alias.<Name>.html` page.
+    Alias,
+}
+
+/// Represents a path type in WGSL, which may include module information, import status
+/// and generic arguments (e.g., `MyGeneric<f32>`).
+#[derive(Debug)]
 pub struct PathType {
     module: Option<String>,
     name: String,
+    generics: Vec<Type>,
     import_module: ImportModule,
+    kind: TypeKind,
 }
 
 impl PathType {
     /// Creates a new PathType instance (usually from parsed elements).
-    pub fn new(module: Option<String>, name: String) -> PathType {
+    pub fn new(module: Option<String>, name: String, generics: Vec<Type>) -> PathType {
         PathType {
             module,
             name,
+            generics,
             import_module: ImportModule::Undefined,
+            kind: TypeKind::default(),
         }
     }
 
@@ -198,35 +763,74 @@ impl PathType {
         &self.name
     }
 
+    /// Get field `generics` from instance of `PathType`.
+    pub fn generics(&self) -> &[Type] {
+        &self.generics
+    }
+
     /// Get field `import_module` from instance of `PathType`.
     pub fn import_module(&self) -> &ImportModule {
         &self.import_module
     }
+
+    /// Get field `kind` from instance of `PathType`.
+    pub fn kind(&self) -> TypeKind {
+        self.kind
+    }
 }
 
 impl RegisterImports for PathType {
     fn register_imports(&mut self, imports: &[Import]) {
+        for generic in &mut self.generics {
+            generic.register_imports(imports);
+        }
+
         if self.import_module != ImportModule::Undefined {
             return;
         }
 
         if let Some(module_name) = &self.module {
+            // Qualified by the import name, or by the full module path (e.g. `bevy_pbr::forward_io::VertexOutput`)
             for import in imports {
-                if import.registered() && import.name() == module_name {
+                if import.registered()
+                    && (import.name() == module_name
+                        || import.module_path() == Some(module_name)
+                        || import.module_name() == module_name)
+                {
+                    self.import_module = ImportModule::Named(import.name().to_owned());
+                    self.kind = import
+                        .module_item_kind(&self.name)
+                        .and_then(|kind| kind.type_kind())
+                        .unwrap_or_default();
+                }
+            }
+        } else {
+            // Unqualified type, imported by name (e.g. `#import module::{MyType}`)
+            for import in imports.iter().filter(|import| import.registered()) {
+                if let Some(item) = import.item(&self.name) {
                     self.import_module = ImportModule::Named(import.name().to_owned());
+                    self.kind = item
+                        .kind()
+                        .and_then(|kind| kind.type_kind())
+                        .unwrap_or_default();
                 }
             }
         }
     }
 
-    fn register_same_module_types(&mut self, type_names: &[String]) {
+    fn register_same_module_types(&mut self, types: &[(String, TypeKind)]) {
+        for generic in &mut self.generics {
+            generic.register_same_module_types(types);
+        }
+
         if self.import_module != ImportModule::Undefined {
             return;
         }
 
-        for type_name in type_names {
+        for (type_name, kind) in types {
             if *type_name == self.name {
                 self.import_module = ImportModule::This;
+                self.kind = *kind;
             }
         }
     }
@@ -237,8 +841,6 @@ impl RegisterImports for PathType {
 pub struct RenderedType {
     /// Indicates if the type is from the same module.
     pub is_this: bool,
-    /// Indicates if the type is a function pointer (like ptr<function, T>).
-    pub is_function_pointer: bool,
     /// The name of the type.
     pub name: String,
     /// The module from which the type is imported, 
@@ -246,4 +848,20 @@ pub struct RenderedType {
     pub module: Option<String>,
     /// The import module name, if any.
     pub import: Option<String>,
+    /// The kind of the referenced user-defined type, used to link to its page.
+    pub kind: TypeKind,
+    /// The element type, if the type is an array, atomic or pointer.
+    pub element: Option<Box<RenderedType>>,
+    /// The generic arguments, if the type is a generic path type.
+    pub generics: Vec<RenderedType>,
+    /// The WGSL extension which must be enabled to use the type, if any (e.g. `f16`).
+    pub extension: Option<String>,
+    /// Indicates if the type uses a non-standard primitive type, which isn't part of WGSL (e.g. `f64`).
+    pub is_non_standard: bool,
+    /// The size expression, if the type is a fixed-size array.
+    pub size: Option<String>,
+    /// The address space, if the type is a pointer.
+    pub address_space: Option<AddressSpace>,
+    /// The access mode, if the type is a pointer with an explicit one.
+    pub access_mode: Option<AccessMode>,
 }
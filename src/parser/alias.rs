@@ -0,0 +1,55 @@
+//! Module for parsing WGSL type aliases using Pest and converting them into [`Alias`] model.
+
+use super::{error::ParsingError, FromPest, Rule};
+use crate::models::{alias::Alias, types::Type};
+use pest::iterators::Pair;
+
+impl FromPest for Alias {
+    fn from_pest(element: Pair<'_, Rule>) -> Result<Self, ParsingError>
+    where
+        Self: Sized,
+    {
+        match element.as_rule() {
+            Rule::ALIAS => {
+                let mut docs = None;
+                let mut name = String::new();
+                let mut ty = Type::default();
+
+                for alias_element in element.into_inner() {
+                    match alias_element.as_rule() {
+                        Rule::DOCS => {
+                            for docs_element in alias_element.into_inner() {
+                                if docs.is_none() {
+                                    docs = Some(String::new());
+                                }
+
+                                if let Some(docs) = &mut docs {
+                                    if !docs.is_empty() {
+                                        docs.push('\n');
+                                    }
+
+                                    docs.push_str(docs_element.as_span().as_str());
+                                }
+
+                                docs = docs.filter(|s| !s.is_empty());
+                            }
+                        }
+                        Rule::IDENT => {
+                            name = alias_element.as_span().as_str().to_owned();
+                        }
+                        Rule::TYPE => {
+                            ty = Type::from_pest(alias_element)?;
+                        }
+                        _ => {}
+                    }
+                }
+
+                Ok(Alias::new(docs, name, ty))
+            }
+            _ => Err(ParsingError::InvalidPestRule {
+                expected: Rule::ALIAS,
+                found: element.as_rule(),
+            }),
+        }
+    }
+}
@@ -1,7 +1,10 @@
 //! Module for parsing WGSL bindings using Pest and converting them into [`Binding`] model.
 
 use super::{error::ParsingError, FromPest, Rule};
-use crate::models::{binding::Binding, types::Type};
+use crate::models::{
+    binding::Binding,
+    types::{AccessMode, AddressSpace, Type},
+};
 use pest::iterators::Pair;
 
 impl FromPest for Binding {
@@ -14,6 +17,8 @@ impl FromPest for Binding {
                 let mut docs = None;
                 let mut attr_group = 0;
                 let mut attr_binding = 0;
+                let mut address_space = None;
+                let mut access_mode = None;
                 let mut name = String::new();
                 let mut ty = Type::default();
 
@@ -42,6 +47,21 @@ impl FromPest for Binding {
                         Rule::TYPE => {
                             ty = Type::from_pest(const_element)?;
                         }
+                        Rule::VAR_TEMPLATE => {
+                            for template_element in const_element.into_inner() {
+                                match template_element.as_rule() {
+                                    Rule::STORAGE_CLASS => {
+                                        address_space =
+                                            Some(AddressSpace::from_pest(template_element)?);
+                                    }
+                                    Rule::ACCESS_MODE => {
+                                        access_mode =
+                                            Some(AccessMode::from_pest(template_element)?);
+                                    }
+                                    _ => {}
+                                }
+                            }
+                        }
                         Rule::BINDING_ATTRS => {
                             for binding_attr_element in const_element.into_inner() {
                                 match binding_attr_element.as_rule() {
@@ -71,7 +91,15 @@ impl FromPest for Binding {
                     }
                 }
 
-                Ok(Binding::new(docs, attr_group, attr_binding, name, ty))
+                Ok(Binding::new(
+                    docs,
+                    attr_group,
+                    attr_binding,
+                    address_space,
+                    access_mode,
+                    name,
+                    ty,
+                ))
             }
             _ => Err(ParsingError::InvalidPestRule {
                 expected: Rule::RESOURCE_BINDING,
@@ -1,7 +1,10 @@
-//! Module for parsing WGSL constants using Pest and converting them into [`Constant`] model.
+//! Module for parsing WGSL constants using Pest and converting them into [`Constant`] and [`Override`] models.
 
-use super::{error::ParsingError, FromPest, Rule};
-use crate::models::{constant::Constant, types::Type};
+use super::{error::ParsingError, literal::parse_int_literal, FromPest, Rule};
+use crate::models::{
+    constant::{Constant, Override},
+    types::Type,
+};
 use pest::iterators::Pair;
 
 impl FromPest for Constant {
@@ -57,3 +60,67 @@ impl FromPest for Constant {
         }
     }
 }
+
+impl FromPest for Override {
+    fn from_pest(element: Pair<'_, Rule>) -> Result<Self, ParsingError>
+    where
+        Self: Sized,
+    {
+        match element.as_rule() {
+            Rule::OVERRIDE => {
+                let mut docs = None;
+                let mut id = None;
+                let mut name = String::new();
+                let mut ty = None;
+                let mut value = None;
+
+                for override_element in element.into_inner() {
+                    match override_element.as_rule() {
+                        Rule::DOCS => {
+                            for docs_element in override_element.into_inner() {
+                                if docs.is_none() {
+                                    docs = Some(String::new());
+                                }
+
+                                if let Some(docs) = &mut docs {
+                                    if !docs.is_empty() {
+                                        docs.push('\n');
+                                    }
+
+                                    docs.push_str(docs_element.as_span().as_str());
+                                }
+
+                                docs = docs.filter(|s| !s.is_empty());
+                            }
+                        }
+                        Rule::ATTR_ID => {
+                            let id_str = override_element
+                                .into_inner()
+                                .next()
+                                .unwrap()
+                                .as_span()
+                                .as_str();
+                            id = Some(parse_int_literal(id_str)?);
+                        }
+                        Rule::IDENT => {
+                            name = override_element.as_span().as_str().to_owned();
+                        }
+                        Rule::TYPE => {
+                            ty = Some(Type::from_pest(override_element)?);
+                        }
+                        Rule::CONST_VALUE => {
+                            value = Some(override_element.as_span().as_str().to_owned());
+                        }
+                        _ => {}
+                    }
+                }
+
+                Ok(Override::new(docs, id, name, ty, value))
+            }
+            _ => Err(ParsingError::InvalidPestRule {
+                expected: Rule::OVERRIDE,
+                found: element.as_rule(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Module for parsing WGSL global directives using Pest and converting them into [`Directive`] model.
+
+use super::{error::ParsingError, FromPest, Rule};
+use crate::models::directive::{Diagnostic, DiagnosticSeverity, Directive};
+use pest::iterators::Pair;
+use std::str::FromStr;
+use thiserror::Error;
+
+impl FromPest for Directive {
+    fn from_pest(element: Pair<'_, Rule>) -> Result<Self, ParsingError>
+    where
+        Self: Sized,
+    {
+        match element.as_rule() {
+            Rule::ENABLE_DIRECTIVE => Ok(Directive::Enable(directive_names(element))),
+            Rule::REQUIRES_DIRECTIVE => Ok(Directive::Requires(directive_names(element))),
+            Rule::DIAGNOSTIC_DIRECTIVE => {
+                Ok(Directive::Diagnostic(Diagnostic::from_pest(element)?))
+            }
+            _ => Err(ParsingError::InvalidPestRule {
+                expected: Rule::ENABLE_DIRECTIVE,
+                found: element.as_rule(),
+            }),
+        }
+    }
+}
+
+fn directive_names(element: Pair<'_, Rule>) -> Vec<String> {
+    element
+        .into_inner()
+        .filter(|name_element| name_element.as_rule() == Rule::DIRECTIVE_NAME)
+        .map(|name_element| name_element.as_span().as_str().to_owned())
+        .collect()
+}
+
+impl FromPest for Diagnostic {
+    fn from_pest(element: Pair<'_, Rule>) -> Result<Self, ParsingError>
+    where
+        Self: Sized,
+    {
+        match element.as_rule() {
+            Rule::DIAGNOSTIC_DIRECTIVE => {
+                let mut severity = DiagnosticSeverity::default();
+                let mut rule = String::new();
+
+                for diagnostic_element in element.into_inner() {
+                    match diagnostic_element.as_rule() {
+                        Rule::DIAGNOSTIC_SEVERITY => {
+                            severity = DiagnosticSeverity::from_str(
+                                diagnostic_element.as_span().as_str(),
+                            )?;
+                        }
+                        Rule::DIAGNOSTIC_RULE => {
+                            rule = diagnostic_element.as_span().as_str().to_owned();
+                        }
+                        _ => {}
+                    }
+                }
+
+                Ok(Diagnostic::new(severity, rule))
+            }
+            _ => Err(ParsingError::InvalidPestRule {
+                expected: Rule::DIAGNOSTIC_DIRECTIVE,
+                found: element.as_rule(),
+            }),
+        }
+    }
+}
+
+/// Error for invalid diagnostic severities during parsing.
+#[derive(Debug, Error)]
+#[error("Invalid diagnostic severity `{0}`; available are error, warning, info, off")]
+pub struct InvalidDiagnosticSeverity(String);
+
+impl FromStr for DiagnosticSeverity {
+    type Err = InvalidDiagnosticSeverity;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use DiagnosticSeverity::*;
+
+        match s {
+            "error" => Ok(Error),
+            "warning" => Ok(Warning),
+            "info" => Ok(Info),
+            "off" => Ok(Off),
+            _ => Err(InvalidDiagnosticSeverity(s.to_owned())),
+        }
+    }
+}
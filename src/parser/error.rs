@@ -1,7 +1,14 @@
 //! Module containing error types for parsing WGSL components using Pest.
 
 use super::{
-    types::{InvalidPrimitiveType, InvalidVectorDimension},
+    directive::InvalidDiagnosticSeverity,
+    function::InvalidShaderStage,
+    literal::InvalidIntegerLiteral,
+    preprocessor::PreprocessorError,
+    types::{
+        InvalidAccessMode, InvalidAddressSpace, InvalidPrimitiveType, InvalidTextureDimension,
+        InvalidVectorDimension,
+    },
     Rule,
 };
 use thiserror::Error;
@@ -17,12 +24,39 @@ pub enum ParsingError {
         /// The found pest rule.
         found: Rule,
     },
+    /// Error for pest rules missing an expected inner element (e.g. an attribute argument) during parsing.
+    #[error("Missing inner element of pest rule `{rule:?}`")]
+    MissingInnerElement {
+        /// The pest rule missing the inner element.
+        rule: Rule,
+    },
     /// Error for invalid primitive types during parsing.
     #[error(transparent)]
     InvalidPrimitiveType(#[from] InvalidPrimitiveType),
     /// Error for invalid vector dimensions during parsing.
     #[error(transparent)]
     InvalidVectorDimension(#[from] InvalidVectorDimension),
+    /// Error for invalid address spaces during parsing.
+    #[error(transparent)]
+    InvalidAddressSpace(#[from] InvalidAddressSpace),
+    /// Error for invalid texture dimensions during parsing.
+    #[error(transparent)]
+    InvalidTextureDimension(#[from] InvalidTextureDimension),
+    /// Error for invalid access modes during parsing.
+    #[error(transparent)]
+    InvalidAccessMode(#[from] InvalidAccessMode),
+    /// Error for invalid diagnostic severities during parsing.
+    #[error(transparent)]
+    InvalidDiagnosticSeverity(#[from] InvalidDiagnosticSeverity),
+    /// Error for invalid shader stages during parsing.
+    #[error(transparent)]
+    InvalidShaderStage(#[from] InvalidShaderStage),
+    /// Error for invalid or out of range integer literals in attributes during parsing.
+    #[error(transparent)]
+    InvalidIntegerLiteral(#[from] InvalidIntegerLiteral),
+    /// Error preprocessing conditional compilation directives.
+    #[error(transparent)]
+    PreprocessorError(#[from] PreprocessorError),
     /// Error parsing shader input.
     #[error("Error parsing shader input")]
     InputParsingError(#[from] Box<pest::error::Error<Rule>>),
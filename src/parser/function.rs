@@ -2,10 +2,13 @@
 
 use super::{error::ParsingError, FromPest, Rule};
 use crate::models::{
-    function::{Arg, Function, FunctionType},
-    types::{PathType, Primitive, Type, Vector},
+    function::{Arg, Function, FunctionModifier, FunctionType, ShaderStage},
+    structure::MemberAttribute,
+    types::Type,
 };
 use pest::iterators::Pair;
+use std::str::FromStr;
+use thiserror::Error;
 
 impl FromPest for Function {
     fn from_pest(element: Pair<'_, Rule>) -> Result<Self, ParsingError>
@@ -15,9 +18,14 @@ impl FromPest for Function {
         match element.as_rule() {
             Rule::FUNCTION => {
                 let mut docs = None;
+                let mut stage = None;
+                let mut workgroup_size = vec![];
+                let mut modifier = None;
                 let mut name = String::new();
                 let mut args = vec![];
                 let mut return_ty = None;
+                let mut return_attributes = vec![];
+                let mut references: Vec<String> = vec![];
 
                 for function_element in element.into_inner() {
                     match function_element.as_rule() {
@@ -38,6 +46,35 @@ impl FromPest for Function {
                                 docs = docs.filter(|s| !s.is_empty());
                             }
                         }
+                        Rule::ENTRY => {
+                            stage = Some(ShaderStage::from_pest(
+                                function_element.into_inner().next().unwrap(),
+                            )?);
+                        }
+                        Rule::WORKGROUP_SIZE => {
+                            workgroup_size = function_element
+                                .into_inner()
+                                .map(|dimension| dimension.as_span().as_str().trim().to_owned())
+                                .collect();
+                        }
+                        Rule::FUNCTION_MODIFIER => {
+                            modifier = match function_element.as_span().as_str() {
+                                "virtual" => Some(FunctionModifier::Virtual {
+                                    overridden_by: vec![],
+                                }),
+                                _ => Some(FunctionModifier::Override {
+                                    module_path: String::new(),
+                                    module: None,
+                                }),
+                            };
+                        }
+                        Rule::OVERRIDDEN_MODULE => {
+                            if let Some(FunctionModifier::Override { module_path, .. }) =
+                                &mut modifier
+                            {
+                                *module_path = function_element.as_span().as_str().to_owned();
+                            }
+                        }
                         Rule::IDENT => {
                             name = function_element.as_span().as_str().to_owned();
                         }
@@ -55,15 +92,41 @@ impl FromPest for Function {
                             }
                         }
                         Rule::RETURN => {
-                            return_ty = Some(Type::from_pest(
-                                function_element.into_inner().next().unwrap(),
-                            )?)
+                            for return_element in function_element.into_inner() {
+                                match return_element.as_rule() {
+                                    Rule::MEMBER_ATTR => {
+                                        return_attributes
+                                            .push(MemberAttribute::from_pest(return_element)?);
+                                    }
+                                    Rule::TYPE => {
+                                        return_ty = Some(Type::from_pest(return_element)?);
+                                    }
+                                    _ => {}
+                                }
+                            }
+                        }
+                        Rule::REFERENCE => {
+                            let reference = function_element.as_span().as_str();
+
+                            if !references.iter().any(|r| r == reference) {
+                                references.push(reference.to_owned());
+                            }
                         }
                         _ => {}
                     }
                 }
 
-                Ok(Function::new(docs, name, args, return_ty))
+                Ok(Function::new(
+                    docs,
+                    stage,
+                    workgroup_size,
+                    modifier,
+                    name,
+                    args,
+                    return_ty,
+                    return_attributes,
+                    references,
+                ))
             }
             _ => Err(ParsingError::InvalidPestRule {
                 expected: Rule::FUNCTION,
@@ -81,6 +144,7 @@ impl FromPest for Arg {
         match element.as_rule() {
             Rule::ARG => {
                 let mut docs = None;
+                let mut attributes = vec![];
                 let mut name = String::new();
                 let mut ty = FunctionType::default();
 
@@ -92,17 +156,20 @@ impl FromPest for Arg {
                                     .filter(|s| !s.is_empty());
                             }
                         }
+                        Rule::MEMBER_ATTR => {
+                            attributes.push(MemberAttribute::from_pest(arg_element)?);
+                        }
                         Rule::IDENT => {
                             name = arg_element.as_span().as_str().to_owned();
                         }
-                        Rule::FUNCTION_TYPE => {
+                        Rule::TYPE => {
                             ty = FunctionType::from_pest(arg_element)?;
                         }
                         _ => {}
                     }
                 }
 
-                Ok(Arg::new(docs, name, ty))
+                Ok(Arg::new(docs, attributes, name, ty))
             }
             _ => Err(ParsingError::InvalidPestRule {
                 expected: Rule::ARG,
@@ -112,39 +179,35 @@ impl FromPest for Arg {
     }
 }
 
-impl FromPest for FunctionType {
+/// Error for invalid shader stages during parsing.
+#[derive(Debug, Error)]
+#[error("Invalid shader stage `{0}`; available are vertex, fragment, compute")]
+pub struct InvalidShaderStage(String);
+
+impl FromStr for ShaderStage {
+    type Err = InvalidShaderStage;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use ShaderStage::*;
+
+        match s {
+            "vertex" => Ok(Vertex),
+            "fragment" => Ok(Fragment),
+            "compute" => Ok(Compute),
+            _ => Err(InvalidShaderStage(s.to_owned())),
+        }
+    }
+}
+
+impl FromPest for ShaderStage {
     fn from_pest(element: Pair<'_, Rule>) -> Result<Self, ParsingError>
     where
         Self: Sized,
     {
         match element.as_rule() {
-            Rule::FUNCTION_TYPE => {
-                let mut ty = FunctionType::default();
-
-                for type_element in element.into_inner() {
-                    match type_element.as_rule() {
-                        Rule::PRIMITIVE => {
-                            ty = FunctionType::Primitive(Primitive::from_pest(type_element)?);
-                        }
-                        Rule::VECTOR => {
-                            ty = FunctionType::Vector(Vector::from_pest(type_element)?);
-                        }
-                        Rule::PATH_TYPE => {
-                            ty = FunctionType::Path(PathType::from_pest(type_element)?);
-                        }
-                        Rule::FUNCTION_POINTER => {
-                            ty = FunctionType::FunctionPointer(Type::from_pest(
-                                type_element.into_inner().next().unwrap(),
-                            )?);
-                        }
-                        _ => {}
-                    }
-                }
-
-                Ok(ty)
-            }
+            Rule::SHADER_STAGE => Ok(ShaderStage::from_str(element.as_span().as_str())?),
             _ => Err(ParsingError::InvalidPestRule {
-                expected: Rule::FUNCTION_TYPE,
+                expected: Rule::SHADER_STAGE,
                 found: element.as_rule(),
             }),
         }
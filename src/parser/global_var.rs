@@ -0,0 +1,66 @@
+//! Module for parsing module-scope WGSL variables using Pest and converting them into [`GlobalVar`] model.
+
+use super::{error::ParsingError, FromPest, Rule};
+use crate::models::{
+    global_var::GlobalVar,
+    types::{AddressSpace, Type},
+};
+use pest::iterators::Pair;
+
+impl FromPest for GlobalVar {
+    fn from_pest(element: Pair<'_, Rule>) -> Result<Self, ParsingError>
+    where
+        Self: Sized,
+    {
+        match element.as_rule() {
+            Rule::GLOBAL_VAR => {
+                let mut docs = None;
+                let mut address_space = AddressSpace::default();
+                let mut name = String::new();
+                let mut ty = None;
+                let mut value = None;
+
+                for var_element in element.into_inner() {
+                    match var_element.as_rule() {
+                        Rule::DOCS => {
+                            for docs_element in var_element.into_inner() {
+                                if docs.is_none() {
+                                    docs = Some(String::new());
+                                }
+
+                                if let Some(docs) = &mut docs {
+                                    if !docs.is_empty() {
+                                        docs.push('\n');
+                                    }
+
+                                    docs.push_str(docs_element.as_span().as_str());
+                                }
+
+                                docs = docs.filter(|s| !s.is_empty());
+                            }
+                        }
+                        Rule::GLOBAL_VAR_ADDRESS_SPACE => {
+                            address_space = AddressSpace::from_pest(var_element)?;
+                        }
+                        Rule::IDENT => {
+                            name = var_element.as_span().as_str().to_owned();
+                        }
+                        Rule::TYPE => {
+                            ty = Some(Type::from_pest(var_element)?);
+                        }
+                        Rule::CONST_VALUE => {
+                            value = Some(var_element.as_span().as_str().to_owned());
+                        }
+                        _ => {}
+                    }
+                }
+
+                Ok(GlobalVar::new(docs, address_space, name, ty, value))
+            }
+            _ => Err(ParsingError::InvalidPestRule {
+                expected: Rule::GLOBAL_VAR,
+                found: element.as_rule(),
+            }),
+        }
+    }
+}
@@ -47,6 +47,61 @@ impl FromPest for Import {
 
                 Ok(Import::new(docs, path, name))
             }
+            Rule::BUILTIN_IMPORT => {
+                let mut docs = None;
+                let mut module_path = String::new();
+                let mut alias = None;
+                let mut items = vec![];
+
+                for import_element in element.into_inner() {
+                    match import_element.as_rule() {
+                        Rule::DOCS => {
+                            for docs_element in import_element.into_inner() {
+                                if docs.is_none() {
+                                    docs = Some(String::new());
+                                }
+
+                                if let Some(docs) = &mut docs {
+                                    if !docs.is_empty() {
+                                        docs.push('\n');
+                                    }
+
+                                    docs.push_str(docs_element.as_span().as_str());
+                                }
+
+                                docs = docs.filter(|s| !s.is_empty());
+                            }
+                        }
+                        Rule::BUILTIN_IMPORT_CONTENT => {
+                            for content_element in import_element.into_inner() {
+                                match content_element.as_rule() {
+                                    Rule::IMPORT_PATH | Rule::MODULE_NAME => {
+                                        if !module_path.is_empty() {
+                                            module_path.push_str("::");
+                                        }
+
+                                        module_path.push_str(content_element.as_span().as_str());
+                                    }
+                                    Rule::IMPORT_LIST => {
+                                        items.extend(
+                                            content_element
+                                                .into_inner()
+                                                .map(|item| item.as_span().as_str().to_owned()),
+                                        );
+                                    }
+                                    _ => {}
+                                }
+                            }
+                        }
+                        Rule::MODULE_NAME => {
+                            alias = Some(import_element.as_span().as_str().to_owned());
+                        }
+                        _ => {}
+                    }
+                }
+
+                Ok(Import::builtin(docs, module_path, alias, items))
+            }
             _ => Err(ParsingError::InvalidPestRule {
                 expected: Rule::IMPORT,
                 found: element.as_rule(),
@@ -54,3 +109,119 @@ impl FromPest for Import {
         }
     }
 }
+
+/// Relative path segments of WESL imports, referring to the package root or the parent module.
+const WESL_RELATIVE_SEGMENTS: [&str; 2] = ["package", "super"];
+
+impl Import {
+    /// Parses a WESL import statement into imports of the listed paths, e.g.
+    /// `import package::lighting::{pbr, shadows};` imports items `pbr` and `shadows`
+    /// of module `package::lighting`. Paths outside of collections import either a module
+    /// or a single item, which is resolved during registration.
+    pub fn from_wesl_pest(element: Pair<'_, Rule>) -> Result<Vec<Import>, ParsingError> {
+        if element.as_rule() != Rule::WESL_IMPORT {
+            return Err(ParsingError::InvalidPestRule {
+                expected: Rule::WESL_IMPORT,
+                found: element.as_rule(),
+            });
+        }
+
+        let mut docs = None;
+        let mut paths = vec![];
+
+        for import_element in element.into_inner() {
+            match import_element.as_rule() {
+                Rule::DOCS => {
+                    for docs_element in import_element.into_inner() {
+                        if docs.is_none() {
+                            docs = Some(String::new());
+                        }
+
+                        if let Some(docs) = &mut docs {
+                            if !docs.is_empty() {
+                                docs.push('\n');
+                            }
+
+                            docs.push_str(docs_element.as_span().as_str());
+                        }
+
+                        docs = docs.filter(|s| !s.is_empty());
+                    }
+                }
+                Rule::WESL_IMPORT_PATH | Rule::WESL_IMPORT_COLLECTION => {
+                    collect_wesl_paths(import_element, &[], false, &mut paths);
+                }
+                _ => {}
+            }
+        }
+
+        // Items of collections are grouped by the module they are imported from
+        let mut imports: Vec<(String, Vec<String>)> = vec![];
+
+        for (mut path, in_collection) in paths {
+            let is_item = in_collection
+                && path[..path.len() - 1]
+                    .iter()
+                    .any(|segment| !WESL_RELATIVE_SEGMENTS.contains(&segment.as_str()));
+
+            if !is_item {
+                imports.push((path.join("::"), vec![]));
+                continue;
+            }
+
+            let item = path.pop().unwrap_or_default();
+            let module_path = path.join("::");
+
+            match imports
+                .iter_mut()
+                .find(|(path, items)| *path == module_path && !items.is_empty())
+            {
+                Some((_, items)) => items.push(item),
+                None => imports.push((module_path, vec![item])),
+            }
+        }
+
+        Ok(imports
+            .into_iter()
+            .map(|(module_path, items)| Import::builtin(docs.clone(), module_path, None, items))
+            .collect())
+    }
+}
+
+/// Collects the full paths of a WESL import path or collection,
+/// along with whether each path is listed in a collection.
+fn collect_wesl_paths(
+    element: Pair<'_, Rule>,
+    prefix: &[String],
+    in_collection: bool,
+    paths: &mut Vec<(Vec<String>, bool)>,
+) {
+    match element.as_rule() {
+        Rule::WESL_IMPORT_COLLECTION => {
+            for path_element in element.into_inner() {
+                collect_wesl_paths(path_element, prefix, true, paths);
+            }
+        }
+        Rule::WESL_IMPORT_PATH => {
+            let mut path = prefix.to_vec();
+            let mut is_leaf = true;
+
+            for path_element in element.into_inner() {
+                match path_element.as_rule() {
+                    Rule::WESL_IMPORT_SEGMENT => {
+                        path.push(path_element.as_span().as_str().to_owned());
+                    }
+                    _ => {
+                        is_leaf = false;
+                        collect_wesl_paths(path_element, &path, in_collection, paths);
+                    }
+                }
+            }
+
+            if is_leaf {
+                paths.push((path, in_collection));
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,26 @@
+//! Module for parsing WGSL literals used in attributes, e.g. `@id(0x10)` or `@align(16u)`.
+
+use thiserror::Error;
+
+/// Error for integer literals which are invalid or out of range for their attribute.
+#[derive(Debug, Error)]
+#[error("Invalid or out of range integer literal `{0}`")]
+pub struct InvalidIntegerLiteral(String);
+
+/// Parses a WGSL integer literal, either decimal or hexadecimal (`0x`),
+/// optionally with the `i` or `u` suffix, into an integer of type `T`.
+pub fn parse_int_literal<T: TryFrom<u64>>(literal: &str) -> Result<T, InvalidIntegerLiteral> {
+    let invalid = || InvalidIntegerLiteral(literal.to_owned());
+    let digits = literal.trim_end_matches(['i', 'u']);
+
+    let value = match digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => digits.parse(),
+    }
+    .map_err(|_| invalid())?;
+
+    T::try_from(value).map_err(|_| invalid())
+}
@@ -3,21 +3,37 @@
 
 //! WGSL parser module. Parses WGSL shader code into Rust structures,
 //! representing various shader module elements like functions,
-//! structures, bindings, constants, and imports.
+//! structures, aliases, bindings, global variables, constants, directives and imports.
 
 use crate::models::{
-    binding::Binding, constant::Constant, function::Function, import::Import, structure::Structure,
+    alias::Alias,
+    binding::Binding,
+    condition::add_alternative,
+    constant::{Constant, Override},
+    directive::Directive,
+    function::Function,
+    global_var::GlobalVar,
+    import::{Import, ItemKind},
+    skipped_item::SkippedItem,
+    structure::Structure,
     Wgsl,
 };
 use error::ParsingError;
-use pest::{iterators::Pair, Parser};
+use pest::{error::LineColLocation, iterators::Pair, Parser};
 use pest_derive::Parser;
+use preprocessor::Preprocessor;
+use std::collections::HashMap;
 
+pub mod alias;
 pub mod binding;
 pub mod constant;
+pub mod directive;
 pub mod error;
 pub mod function;
+pub mod global_var;
 pub mod import;
+pub mod literal;
+pub mod preprocessor;
 pub mod structure;
 pub mod types;
 
@@ -39,95 +55,338 @@ pub struct WgslParser;
 
 impl WgslParser {
     /// Parses the provided WGSL shader code and returns a `Wgsl` structure
-    /// with all shader module elements.
+    /// with all shader module elements. The code of every conditional compilation branch
+    /// is kept. The module is named `shader_name`, unless it declares its own import path
+    /// with `#define_import_path`.
     pub fn parse(shader_name: &str, shader: &str) -> Result<Wgsl, ParsingError> {
-        let shader_elements = WgslParserInner::parse(Rule::SHADER, shader)
+        WgslParser::parse_with_preprocessor(shader_name, shader, &Preprocessor::new())
+    }
+
+    /// Parses the provided WGSL shader code like [`WgslParser::parse`],
+    /// handling conditional compilation directives with the given [`Preprocessor`] beforehand.
+    pub fn parse_with_preprocessor(
+        shader_name: &str,
+        shader: &str,
+        preprocessor: &Preprocessor,
+    ) -> Result<Wgsl, ParsingError> {
+        WgslParser::parse_shader(shader_name, shader, preprocessor, false)
+    }
+
+    /// Parses the provided WGSL shader code like [`WgslParser::parse_with_preprocessor`] in recovery mode.
+    /// Top-level items which can't be parsed are skipped up to the next item boundary
+    /// and recorded in [`Wgsl::skipped_items`], so only preprocessing errors are returned.
+    pub fn parse_recovering(
+        shader_name: &str,
+        shader: &str,
+        preprocessor: &Preprocessor,
+    ) -> Result<Wgsl, ParsingError> {
+        WgslParser::parse_shader(shader_name, shader, preprocessor, true)
+    }
+
+    fn parse_shader(
+        shader_name: &str,
+        shader: &str,
+        preprocessor: &Preprocessor,
+        recover: bool,
+    ) -> Result<Wgsl, ParsingError> {
+        let preprocessed = preprocessor.process(shader)?;
+        let rule = if recover {
+            Rule::RECOVERING_SHADER
+        } else {
+            Rule::SHADER
+        };
+        let shader_elements = WgslParserInner::parse(rule, preprocessed.source())
             .map_err(|e| ParsingError::InputParsingError(Box::new(e)))?;
 
         let source_code = shader.to_owned();
+        let mut module_name = shader_name.to_string();
+        let mut import_path = None;
         let mut global_docs = None;
+        let mut directives = vec![];
         let mut imports = vec![];
         let mut functions = vec![];
         let mut structures = vec![];
+        let mut aliases = vec![];
         let mut constants = vec![];
+        let mut overrides = vec![];
         let mut bindings = vec![];
+        let mut global_vars = vec![];
+        let mut conditions = HashMap::new();
+        let mut skipped_items = vec![];
 
         for shader_element in shader_elements {
-            match shader_element.as_rule() {
-                Rule::RESOURCE_BINDING => {
-                    let binding = Binding::from_pest(shader_element)?;
+            // Doc comments of an item may precede the conditional block, so its last line is used.
+            // The span itself may end with whitespace reaching into the next branch
+            let span = shader_element.as_span();
+            let line =
+                span.start_pos().line_col().0 + span.as_str().trim_end().matches('\n').count();
+            let item_conditions = preprocessed.conditions_at(line);
+            // Branches of conditional blocks commonly declare alternatives of the same item
+            let warn_duplicates = item_conditions.is_empty();
+            // Items declared again in another branch are available under the conditions of either branch
+            let mut record_conditions = |kind: ItemKind, name: &str, already_declared: bool| {
+                let key = (kind, name.to_owned());
 
-                    if bindings.contains(&binding) {
-                        log::warn!("Binding with name `{}` already exists!", binding.name());
-                    } else {
-                        bindings.push(binding);
+                match conditions.get_mut(&key) {
+                    Some(alternatives) => {
+                        add_alternative(alternatives, item_conditions.to_vec());
+
+                        if alternatives.iter().any(Vec::is_empty) {
+                            conditions.remove(&key);
+                        }
                     }
+                    None if !already_declared && !item_conditions.is_empty() => {
+                        conditions.insert(key, vec![item_conditions.to_vec()]);
+                    }
+                    None => {}
                 }
-                Rule::STRUCTURE => {
-                    let structure = Structure::from_pest(shader_element)?;
+            };
+
+            // Items matched by the grammar may still fail to convert (e.g. with an unknown type),
+            // so in recovery mode they're skipped as well
+            let source = shader_element.as_str().trim_end().to_owned();
+            let start_line = span.start_pos().line_col().0;
 
-                    if structures.contains(&structure) {
-                        log::warn!("Structure with name `{}` already exists!", structure.name());
-                    } else {
-                        structures.push(structure);
+            let parse_element = || -> Result<(), ParsingError> {
+                match shader_element.as_rule() {
+                    Rule::DEFINE_IMPORT_PATH => {
+                        let path = shader_element.into_inner().as_str().to_string();
+
+                        if let Some(import_path) = &import_path {
+                            log::warn!(
+                                "Import path of module `{}` is already defined as `{}`!",
+                                shader_name,
+                                import_path
+                            );
+                        } else {
+                            module_name = path.clone();
+                            import_path = Some(path);
+                        }
                     }
-                }
-                Rule::FUNCTION => {
-                    let function = Function::from_pest(shader_element)?;
+                    Rule::ENABLE_DIRECTIVE
+                    | Rule::REQUIRES_DIRECTIVE
+                    | Rule::DIAGNOSTIC_DIRECTIVE => {
+                        directives.push(Directive::from_pest(shader_element)?);
+                    }
+                    Rule::RESOURCE_BINDING => {
+                        let binding = Binding::from_pest(shader_element)?;
 
-                    if functions.contains(&function) {
-                        log::warn!("Function with name `{}` already exists!", function.name());
-                    } else {
-                        functions.push(function);
+                        if bindings.contains(&binding) {
+                            if warn_duplicates {
+                                log::warn!(
+                                    "Binding with name `{}` already exists!",
+                                    binding.name()
+                                );
+                            }
+                            record_conditions(ItemKind::Binding, binding.name(), true);
+                        } else {
+                            record_conditions(ItemKind::Binding, binding.name(), false);
+                            bindings.push(binding);
+                        }
                     }
-                }
-                Rule::IMPORT => {
-                    let import = Import::from_pest(shader_element)?;
+                    Rule::GLOBAL_VAR => {
+                        let global_var = GlobalVar::from_pest(shader_element)?;
 
-                    if imports.contains(&import) {
-                        log::warn!("Import with name `{}` already exists!", import.name());
-                    } else {
-                        imports.push(import);
+                        if global_vars.contains(&global_var) {
+                            if warn_duplicates {
+                                log::warn!(
+                                    "Global variable with name `{}` already exists!",
+                                    global_var.name()
+                                );
+                            }
+                            record_conditions(ItemKind::GlobalVar, global_var.name(), true);
+                        } else {
+                            record_conditions(ItemKind::GlobalVar, global_var.name(), false);
+                            global_vars.push(global_var);
+                        }
                     }
-                }
-                Rule::CONST => {
-                    let constant = Constant::from_pest(shader_element)?;
-                    if constants.contains(&constant) {
-                        log::warn!("Constant with name `{}` already exists!", constant.name());
-                    } else {
-                        constants.push(constant);
+                    Rule::STRUCTURE => {
+                        let structure = Structure::from_pest(shader_element)?;
+
+                        if structures.contains(&structure) {
+                            if warn_duplicates {
+                                log::warn!(
+                                    "Structure with name `{}` already exists!",
+                                    structure.name()
+                                );
+                            }
+                            record_conditions(ItemKind::Struct, structure.name(), true);
+                        } else {
+                            record_conditions(ItemKind::Struct, structure.name(), false);
+                            structures.push(structure);
+                        }
                     }
-                }
-                Rule::GLOBAL_DOCS => {
-                    for docs_element in shader_element.into_inner() {
-                        if global_docs.is_none() {
-                            global_docs = Some(String::new());
+                    Rule::ALIAS => {
+                        let alias = Alias::from_pest(shader_element)?;
+
+                        if aliases.contains(&alias) {
+                            if warn_duplicates {
+                                log::warn!("Alias with name `{}` already exists!", alias.name());
+                            }
+                            record_conditions(ItemKind::Alias, alias.name(), true);
+                        } else {
+                            record_conditions(ItemKind::Alias, alias.name(), false);
+                            aliases.push(alias);
                         }
+                    }
+                    Rule::FUNCTION => {
+                        let function = Function::from_pest(shader_element)?;
 
-                        if let Some(global_docs) = &mut global_docs {
-                            if !global_docs.is_empty() {
-                                global_docs.push('\n');
+                        if functions.contains(&function) {
+                            if warn_duplicates {
+                                log::warn!(
+                                    "Function with name `{}` already exists!",
+                                    function.name()
+                                );
                             }
+                            record_conditions(ItemKind::Function, function.name(), true);
+                        } else {
+                            record_conditions(ItemKind::Function, function.name(), false);
+                            functions.push(function);
+                        }
+                    }
+                    Rule::IMPORT => {
+                        let import = Import::from_pest(shader_element)?;
 
-                            global_docs.push_str(docs_element.as_span().as_str());
+                        if imports.contains(&import) {
+                            log::warn!("Import with name `{}` already exists!", import.name());
+                        } else {
+                            imports.push(import);
                         }
+                    }
+                    Rule::BUILTIN_IMPORT => {
+                        let import = Import::from_pest(shader_element)?;
+
+                        // The same module may be imported several times with different items
+                        let duplicate = imports.iter().any(|i: &Import| {
+                            i.module_path() == import.module_path() && i.items() == import.items()
+                        });
+
+                        if duplicate {
+                            log::warn!(
+                                "Import of `{}` already exists!",
+                                import.module_path().unwrap_or_default()
+                            );
+                        } else {
+                            imports.push(import);
+                        }
+                    }
+                    Rule::WESL_IMPORT => {
+                        for import in Import::from_wesl_pest(shader_element)? {
+                            let duplicate = imports.iter().any(|i: &Import| {
+                                i.module_path() == import.module_path()
+                                    && i.items() == import.items()
+                            });
 
-                        global_docs = global_docs.filter(|s| !s.is_empty());
+                            if duplicate {
+                                log::warn!(
+                                    "Import of `{}` already exists!",
+                                    import.module_path().unwrap_or_default()
+                                );
+                            } else {
+                                imports.push(import);
+                            }
+                        }
                     }
+                    Rule::CONST => {
+                        let constant = Constant::from_pest(shader_element)?;
+                        if constants.contains(&constant) {
+                            if warn_duplicates {
+                                log::warn!(
+                                    "Constant with name `{}` already exists!",
+                                    constant.name()
+                                );
+                            }
+                            record_conditions(ItemKind::Constant, constant.name(), true);
+                        } else {
+                            record_conditions(ItemKind::Constant, constant.name(), false);
+                            constants.push(constant);
+                        }
+                    }
+                    Rule::OVERRIDE => {
+                        let override_decl = Override::from_pest(shader_element)?;
+                        if overrides.contains(&override_decl) {
+                            if warn_duplicates {
+                                log::warn!(
+                                    "Override with name `{}` already exists!",
+                                    override_decl.name()
+                                );
+                            }
+                            record_conditions(ItemKind::Override, override_decl.name(), true);
+                        } else {
+                            record_conditions(ItemKind::Override, override_decl.name(), false);
+                            overrides.push(override_decl);
+                        }
+                    }
+                    Rule::SKIPPED_ITEM => {
+                        let message = skipped_item_message(&source, start_line);
+                        skipped_items.push(SkippedItem::new(start_line, source.clone(), message));
+                    }
+                    Rule::GLOBAL_DOCS => {
+                        for docs_element in shader_element.into_inner() {
+                            if global_docs.is_none() {
+                                global_docs = Some(String::new());
+                            }
+
+                            if let Some(global_docs) = &mut global_docs {
+                                if !global_docs.is_empty() {
+                                    global_docs.push('\n');
+                                }
+
+                                global_docs.push_str(docs_element.as_span().as_str());
+                            }
+
+                            global_docs = global_docs.take().filter(|s| !s.is_empty());
+                        }
+                    }
+                    _ => {}
+                }
+
+                Ok(())
+            };
+
+            if let Err(e) = parse_element() {
+                if !recover {
+                    return Err(e);
                 }
-                _ => {}
+
+                skipped_items.push(SkippedItem::new(start_line, source, e.to_string()));
             }
         }
 
         Ok(Wgsl {
-            module_name: shader_name.to_string(),
+            module_name,
+            import_path,
             source_code,
             global_docs,
+            directives,
             imports,
             functions,
             structures,
+            aliases,
             constants,
+            overrides,
             bindings,
+            global_vars,
+            conditions,
+            skipped_items,
         })
     }
 }
+
+/// Describes why a skipped item starting at `line` couldn't be parsed,
+/// using the error of parsing it on its own.
+fn skipped_item_message(source: &str, line: usize) -> String {
+    match WgslParserInner::parse(Rule::SHADER, source) {
+        Err(e) => match e.line_col {
+            LineColLocation::Pos((item_line, col)) | LineColLocation::Span((item_line, col), _) => {
+                format!(
+                    "{} at line {}, column {col}",
+                    e.variant.message(),
+                    line + item_line - 1
+                )
+            }
+        },
+        Ok(_) => "Unrecognized item".to_owned(),
+    }
+}
@@ -0,0 +1,357 @@
+//! Preprocessor module handling naga_oil-style conditional compilation directives
+//! (`#ifdef`, `#ifndef`, `#if`, `#else`, `#endif` and `#define`)
+//! and `#{NAME}` shader def substitutions before the shader is parsed.
+
+use crate::models::condition::{ComparisonOp, Condition, ShaderDefValue, ShaderDefs};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Error for invalid comparison operators in `#if` directives.
+#[derive(Debug, Error)]
+#[error("Invalid comparison operator `{0}`; available are ==, !=, <, <=, >, >=")]
+pub struct InvalidComparisonOperator(String);
+
+impl FromStr for ComparisonOp {
+    type Err = InvalidComparisonOperator;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use ComparisonOp::*;
+
+        match s {
+            "==" => Ok(Equal),
+            "!=" => Ok(NotEqual),
+            "<" => Ok(Less),
+            "<=" => Ok(LessEqual),
+            ">" => Ok(Greater),
+            ">=" => Ok(GreaterEqual),
+            _ => Err(InvalidComparisonOperator(s.to_owned())),
+        }
+    }
+}
+
+/// Error for invalid shader def values.
+#[derive(Debug, Error)]
+#[error("Invalid shader def value `{0}`; expected true, false, an integer or an unsigned integer with the `u` suffix")]
+pub struct InvalidShaderDefValue(String);
+
+impl FromStr for ShaderDefValue {
+    type Err = InvalidShaderDefValue;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(value) = s.parse() {
+            Ok(ShaderDefValue::Bool(value))
+        } else if let Ok(value) = s.parse() {
+            Ok(ShaderDefValue::Int(value))
+        } else if let Some(value) = s.strip_suffix('u').and_then(|s| s.parse().ok()) {
+            Ok(ShaderDefValue::UInt(value))
+        } else {
+            Err(InvalidShaderDefValue(s.to_owned()))
+        }
+    }
+}
+
+/// Error types for preprocessing conditional compilation directives.
+#[derive(Debug, Error)]
+pub enum PreprocessorError {
+    /// Error for a directive which can't be parsed.
+    #[error("Invalid preprocessor directive `{directive}` at line {line}")]
+    InvalidDirective {
+        /// The directive line.
+        directive: String,
+        /// The line number of the directive.
+        line: usize,
+    },
+    /// Error for an `#else` or `#endif` without a matching `#ifdef`, `#ifndef` or `#if`.
+    #[error("Unexpected `{directive}` without matching `#if` at line {line}")]
+    UnexpectedDirective {
+        /// The directive line.
+        directive: String,
+        /// The line number of the directive.
+        line: usize,
+    },
+    /// Error for a conditional block which isn't closed with `#endif`.
+    #[error("Missing `#endif` for conditional block opened at line {line}")]
+    MissingEndif {
+        /// The line number of the opening directive.
+        line: usize,
+    },
+    /// Error for a condition which can't be evaluated for the configured shader defs.
+    #[error("Can't evaluate condition `{condition}` at line {line}: shader def is not defined or has a different type")]
+    UnresolvedCondition {
+        /// The condition.
+        condition: String,
+        /// The line number of the directive.
+        line: usize,
+    },
+    /// Error for a `#{NAME}` substitution of a shader def which isn't defined.
+    #[error("Can't substitute `#{{{name}}}` at line {line}: shader def is not defined")]
+    UndefinedShaderDef {
+        /// The name of the shader def.
+        name: String,
+        /// The line number of the substitution.
+        line: usize,
+    },
+    /// Error for invalid comparison operators in `#if` directives.
+    #[error(transparent)]
+    InvalidComparisonOperator(#[from] InvalidComparisonOperator),
+    /// Error for invalid shader def values in `#define` directives.
+    #[error(transparent)]
+    InvalidShaderDefValue(#[from] InvalidShaderDefValue),
+}
+
+/// Shader source with the preprocessor directives removed,
+/// along with the conditions every line depends on.
+#[derive(Debug)]
+pub struct PreprocessedShader {
+    source: String,
+    line_conditions: Vec<Vec<Condition>>,
+}
+
+impl PreprocessedShader {
+    /// Get field `source` from instance of `PreprocessedShader`.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the conditions line `line` (1-based) depends on.
+    /// Empty if the line is outside of any conditional block.
+    pub fn conditions_at(&self, line: usize) -> &[Condition] {
+        line.checked_sub(1)
+            .and_then(|index| self.line_conditions.get(index))
+            .map_or(&[], Vec::as_slice)
+    }
+}
+
+/// A conditional block opened by `#ifdef`, `#ifndef` or `#if`.
+struct ConditionalBlock {
+    /// Line number of the opening directive.
+    line: usize,
+    /// Conditions of the branches so far, which are negated in the `#else` branch.
+    branches: Vec<Condition>,
+    /// Conditions of the current branch.
+    current: Vec<Condition>,
+    /// Whether the code of the block is kept at all.
+    parent_active: bool,
+    /// Whether the code of the current branch is kept.
+    active: bool,
+    /// Whether one of the branches so far has been selected.
+    taken: bool,
+}
+
+/// Preprocessor for naga_oil-style conditional compilation directives.
+///
+/// Directive lines are blanked out, so that the remaining source keeps its line numbers.
+/// Without shader defs, the code of every branch is kept, annotated with the conditions it depends on.
+/// With shader defs, the conditions are evaluated and the code of disabled branches is blanked out too,
+/// while `#{NAME}` in the enabled code is replaced with the value of the shader def.
+#[derive(Debug, Default)]
+pub struct Preprocessor {
+    shader_defs: Option<ShaderDefs>,
+}
+
+impl Preprocessor {
+    /// Creates a new Preprocessor instance, which keeps the code of every branch.
+    pub fn new() -> Preprocessor {
+        Preprocessor { shader_defs: None }
+    }
+
+    /// Creates a new Preprocessor instance, which only keeps the code enabled for `shader_defs`.
+    /// Shader defs declared with `#define` in the enabled code are added to them.
+    pub fn with_shader_defs(shader_defs: ShaderDefs) -> Preprocessor {
+        Preprocessor {
+            shader_defs: Some(shader_defs),
+        }
+    }
+
+    /// Get field `shader_defs` from instance of `Preprocessor`.
+    pub fn shader_defs(&self) -> Option<&ShaderDefs> {
+        self.shader_defs.as_ref()
+    }
+
+    /// Preprocesses the provided shader code.
+    pub fn process(&self, shader: &str) -> Result<PreprocessedShader, PreprocessorError> {
+        let mut source = String::with_capacity(shader.len());
+        let mut line_conditions = vec![];
+        let mut blocks: Vec<ConditionalBlock> = vec![];
+        let mut shader_defs = self.shader_defs.clone();
+
+        for (index, line) in shader.split_inclusive('\n').enumerate() {
+            let line_number = index + 1;
+            let content = line.trim_end_matches(['\n', '\r']);
+            let directive = content.trim();
+            let active = blocks.last().is_none_or(|block| block.active);
+
+            let unexpected = || PreprocessorError::UnexpectedDirective {
+                directive: directive.to_owned(),
+                line: line_number,
+            };
+            let evaluate = |condition: &Condition| match &shader_defs {
+                Some(shader_defs) => condition.evaluate(shader_defs).ok_or_else(|| {
+                    PreprocessorError::UnresolvedCondition {
+                        condition: condition.to_string(),
+                        line: line_number,
+                    }
+                }),
+                None => Ok(true),
+            };
+
+            if let Some(rest) = directive.strip_prefix("#else") {
+                let block = blocks.last_mut().ok_or_else(unexpected)?;
+                let mut current = block
+                    .branches
+                    .iter()
+                    .map(Condition::negated)
+                    .collect::<Vec<_>>();
+                let mut selected = !block.taken || shader_defs.is_none();
+
+                if !rest.trim().is_empty() {
+                    let condition = parse_condition(rest.trim(), directive, line_number)?;
+
+                    if selected && block.parent_active {
+                        selected = evaluate(&condition)?;
+                    }
+
+                    block.branches.push(condition.clone());
+                    current.push(condition);
+                }
+
+                block.current = current;
+                block.active = block.parent_active && selected;
+                block.taken |= block.active;
+            } else if directive.starts_with("#endif") {
+                blocks.pop().ok_or_else(unexpected)?;
+            } else if directive.starts_with("#if") {
+                let condition = parse_condition(directive, directive, line_number)?;
+                let selected = active && evaluate(&condition)?;
+
+                blocks.push(ConditionalBlock {
+                    line: line_number,
+                    branches: vec![condition.clone()],
+                    current: vec![condition],
+                    parent_active: active,
+                    active: selected,
+                    taken: selected,
+                });
+            } else if let Some(definition) = directive.strip_prefix("#define ") {
+                if let (Some(shader_defs), true) = (&mut shader_defs, active) {
+                    let (name, value) = parse_definition(definition)?;
+                    shader_defs.insert(name, value);
+                }
+            } else if active {
+                match &shader_defs {
+                    Some(shader_defs) => {
+                        source.push_str(&substitute_shader_defs(content, shader_defs, line_number)?)
+                    }
+                    None => source.push_str(content),
+                }
+                line_conditions.push(if shader_defs.is_none() {
+                    blocks
+                        .iter()
+                        .flat_map(|block| block.current.iter().cloned())
+                        .collect()
+                } else {
+                    vec![]
+                });
+                source.push_str(&line[content.len()..]);
+                continue;
+            }
+
+            // Directive lines and disabled code are kept empty to preserve line numbers
+            source.push_str(&line[content.len()..]);
+            line_conditions.push(vec![]);
+        }
+
+        if let Some(block) = blocks.first() {
+            return Err(PreprocessorError::MissingEndif { line: block.line });
+        }
+
+        Ok(PreprocessedShader {
+            source,
+            line_conditions,
+        })
+    }
+}
+
+/// Parses a shader def definition, either `NAME` or `NAME=VALUE`, as passed on the command line.
+/// A shader def without a value is set to `true`.
+pub fn parse_shader_def(
+    definition: &str,
+) -> Result<(String, ShaderDefValue), InvalidShaderDefValue> {
+    match definition.split_once('=') {
+        Some((name, value)) => Ok((name.trim().to_owned(), value.trim().parse()?)),
+        None => Ok((definition.trim().to_owned(), ShaderDefValue::default())),
+    }
+}
+
+/// Replaces every `#{NAME}` in `content` with the value of the shader def `NAME`.
+fn substitute_shader_defs(
+    content: &str,
+    shader_defs: &ShaderDefs,
+    line: usize,
+) -> Result<String, PreprocessorError> {
+    let mut substituted = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while let Some((start, end)) = rest
+        .find("#{")
+        .and_then(|start| Some((start, start + rest[start..].find('}')?)))
+    {
+        let name = &rest[start + 2..end];
+        let value = shader_defs
+            .get(name)
+            .ok_or_else(|| PreprocessorError::UndefinedShaderDef {
+                name: name.to_owned(),
+                line,
+            })?;
+
+        substituted.push_str(&rest[..start]);
+        substituted.push_str(&value.to_string());
+        rest = &rest[end + 1..];
+    }
+    substituted.push_str(rest);
+
+    Ok(substituted)
+}
+
+/// Parses the `NAME [VALUE]` part of a `#define` directive.
+fn parse_definition(definition: &str) -> Result<(String, ShaderDefValue), InvalidShaderDefValue> {
+    let mut words = definition.split_whitespace();
+    let name = words.next().unwrap_or_default().to_owned();
+    let value = words
+        .next()
+        .map(str::parse)
+        .transpose()?
+        .unwrap_or_default();
+
+    Ok((name, value))
+}
+
+/// Parses the condition of an `#ifdef`, `#ifndef` or `#if` directive
+/// (`#else` branches use the same forms without the leading `#`).
+fn parse_condition(
+    condition: &str,
+    directive: &str,
+    line: usize,
+) -> Result<Condition, PreprocessorError> {
+    let invalid = || PreprocessorError::InvalidDirective {
+        directive: directive.to_owned(),
+        line,
+    };
+
+    let condition = condition.trim_start_matches('#');
+    let (keyword, rest) = condition
+        .split_once(char::is_whitespace)
+        .ok_or_else(invalid)?;
+    let words = rest.split_whitespace().collect::<Vec<_>>();
+
+    match (keyword, words.as_slice()) {
+        ("ifdef", [name]) => Ok(Condition::Defined(name.to_string())),
+        ("ifndef", [name]) => Ok(Condition::NotDefined(name.to_string())),
+        ("if", [name, op, value]) => Ok(Condition::Comparison {
+            name: name.to_string(),
+            op: op.parse()?,
+            value: value.to_string(),
+        }),
+        _ => Err(invalid()),
+    }
+}
@@ -1,8 +1,8 @@
 //! Module for parsing WGSL structures using Pest and converting them into [`Structure`] model.
 
-use super::{error::ParsingError, FromPest, Rule};
+use super::{error::ParsingError, literal::parse_int_literal, FromPest, Rule};
 use crate::models::{
-    structure::{Field, Structure},
+    structure::{Field, MemberAttribute, Structure},
     types::Type,
 };
 use pest::iterators::Pair;
@@ -75,6 +75,7 @@ impl FromPest for Field {
         match element.as_rule() {
             Rule::FIELD => {
                 let mut docs = None;
+                let mut attributes = vec![];
                 let mut name = String::new();
                 let mut ty = Type::default();
 
@@ -86,6 +87,9 @@ impl FromPest for Field {
                                     .filter(|s| !s.is_empty());
                             }
                         }
+                        Rule::MEMBER_ATTR => {
+                            attributes.push(MemberAttribute::from_pest(field_element)?);
+                        }
                         Rule::IDENT => {
                             name = field_element.as_span().as_str().to_owned();
                         }
@@ -96,7 +100,7 @@ impl FromPest for Field {
                     }
                 }
 
-                Ok(Field::new(docs, name, ty))
+                Ok(Field::new(docs, attributes, name, ty))
             }
             _ => Err(ParsingError::InvalidPestRule {
                 expected: Rule::FIELD,
@@ -105,3 +109,53 @@ impl FromPest for Field {
         }
     }
 }
+
+impl FromPest for MemberAttribute {
+    fn from_pest(element: Pair<'_, Rule>) -> Result<Self, ParsingError>
+    where
+        Self: Sized,
+    {
+        match element.as_rule() {
+            Rule::MEMBER_ATTR => {
+                let attr_element =
+                    element
+                        .into_inner()
+                        .next()
+                        .ok_or(ParsingError::MissingInnerElement {
+                            rule: Rule::MEMBER_ATTR,
+                        })?;
+                let attr_rule = attr_element.as_rule();
+                let mut args = attr_element
+                    .into_inner()
+                    .map(|arg| arg.as_span().as_str().to_owned());
+                let mut arg = || {
+                    args.next()
+                        .ok_or(ParsingError::MissingInnerElement { rule: attr_rule })
+                };
+                let number =
+                    |arg: String| -> Result<u32, ParsingError> { Ok(parse_int_literal(&arg)?) };
+
+                match attr_rule {
+                    Rule::ATTR_ALIGN => Ok(MemberAttribute::Align(number(arg()?)?)),
+                    Rule::ATTR_SIZE => Ok(MemberAttribute::Size(number(arg()?)?)),
+                    Rule::ATTR_LOCATION => Ok(MemberAttribute::Location(number(arg()?)?)),
+                    Rule::ATTR_BUILTIN => Ok(MemberAttribute::Builtin(arg()?)),
+                    Rule::ATTR_INTERPOLATE => Ok(MemberAttribute::Interpolate {
+                        ty: arg()?,
+                        // The sampling argument is optional
+                        sampling: arg().ok(),
+                    }),
+                    Rule::ATTR_INVARIANT => Ok(MemberAttribute::Invariant),
+                    found => Err(ParsingError::InvalidPestRule {
+                        expected: Rule::MEMBER_ATTR,
+                        found,
+                    }),
+                }
+            }
+            _ => Err(ParsingError::InvalidPestRule {
+                expected: Rule::MEMBER_ATTR,
+                found: element.as_rule(),
+            }),
+        }
+    }
+}
@@ -1,7 +1,10 @@
 //! Module for parsing WGSL types using Pest and converting them into [`Type`] model.
 
 use super::{error::ParsingError, FromPest, Rule};
-use crate::models::types::{PathType, Primitive, Type, Vector, VectorDimension};
+use crate::models::types::{
+    AccessMode, AddressSpace, Array, Matrix, PathType, Pointer, Primitive, Sampler, Texture,
+    TextureDimension, TextureKind, Type, Vector, VectorDimension,
+};
 use pest::iterators::Pair;
 use std::str::FromStr;
 use thiserror::Error;
@@ -23,8 +26,27 @@ impl FromPest for Type {
                         Rule::VECTOR => {
                             ty = Type::Vector(Vector::from_pest(type_element)?);
                         }
+                        Rule::MATRIX => {
+                            ty = Type::Matrix(Matrix::from_pest(type_element)?);
+                        }
+                        Rule::ARRAY => {
+                            ty = Type::Array(Array::from_pest(type_element)?);
+                        }
+                        Rule::TEXTURE => {
+                            ty = Type::Texture(Texture::from_pest(type_element)?);
+                        }
+                        Rule::SAMPLER => {
+                            ty = Type::Sampler(Sampler::from_pest(type_element)?);
+                        }
+                        Rule::ATOMIC => {
+                            let primitive = type_element.into_inner().next().unwrap();
+                            ty = Type::Atomic(Primitive::from_pest(primitive)?);
+                        }
+                        Rule::POINTER => {
+                            ty = Type::Pointer(Pointer::from_pest(type_element)?);
+                        }
                         Rule::PATH_TYPE => {
-                            ty = Type::Path(PathType::from_pest(type_element)?);
+                            ty = resolve_predeclared_alias(PathType::from_pest(type_element)?);
                         }
                         _ => {}
                     }
@@ -42,7 +64,7 @@ impl FromPest for Type {
 
 /// Error for invalid primitive types during parsing.
 #[derive(Debug, Error)]
-#[error("Invalid primitive type `{0}`; available are bool, f32, f64, u8, u16, u32, u64, i8, i16, i32, i64")]
+#[error("Invalid primitive type `{0}`; available are bool, f16, f32, f64, u8, u16, u32, u64, i8, i16, i32, i64")]
 pub struct InvalidPrimitiveType(String);
 
 impl FromStr for Primitive {
@@ -53,6 +75,7 @@ impl FromStr for Primitive {
 
         match s {
             "bool" => Ok(Bool),
+            "f16" => Ok(Float16),
             "f32" => Ok(Float32),
             "f64" => Ok(Float64),
             "u8" => Ok(Uint8),
@@ -115,6 +138,279 @@ impl FromPest for Vector {
     }
 }
 
+impl FromPest for Matrix {
+    fn from_pest(element: Pair<'_, Rule>) -> Result<Self, ParsingError>
+    where
+        Self: Sized,
+    {
+        match element.as_rule() {
+            Rule::MATRIX => {
+                let mut dimensions = vec![];
+                let mut ty = Primitive::default();
+
+                for matrix_element in element.into_inner() {
+                    match matrix_element.as_rule() {
+                        Rule::VECTOR_DIMENSION => {
+                            dimensions.push(VectorDimension::from_pest(matrix_element)?);
+                        }
+                        Rule::PRIMITIVE => {
+                            ty = Primitive::from_pest(matrix_element)?;
+                        }
+                        _ => {}
+                    }
+                }
+
+                let mut dimensions = dimensions.into_iter();
+                let columns = dimensions.next().unwrap_or_default();
+                let rows = dimensions.next().unwrap_or_default();
+
+                Ok(Matrix::new(columns, rows, ty))
+            }
+            _ => Err(ParsingError::InvalidPestRule {
+                expected: Rule::MATRIX,
+                found: element.as_rule(),
+            }),
+        }
+    }
+}
+
+/// Error for invalid texture dimensions during parsing.
+#[derive(Debug, Error)]
+#[error("Invalid texture dimension `{0}`; available are 1d, 2d, 2d_array, 3d, cube, cube_array")]
+pub struct InvalidTextureDimension(String);
+
+impl FromStr for TextureDimension {
+    type Err = InvalidTextureDimension;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use TextureDimension::*;
+
+        match s {
+            "1d" => Ok(D1),
+            "2d" => Ok(D2),
+            "2d_array" => Ok(D2Array),
+            "3d" => Ok(D3),
+            "cube" => Ok(Cube),
+            "cube_array" => Ok(CubeArray),
+            _ => Err(InvalidTextureDimension(s.to_owned())),
+        }
+    }
+}
+
+/// Error for invalid access modes during parsing.
+#[derive(Debug, Error)]
+#[error("Invalid access mode `{0}`; available are read, write, read_write")]
+pub struct InvalidAccessMode(String);
+
+impl FromStr for AccessMode {
+    type Err = InvalidAccessMode;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use AccessMode::*;
+
+        match s {
+            "read" => Ok(Read),
+            "write" => Ok(Write),
+            "read_write" => Ok(ReadWrite),
+            _ => Err(InvalidAccessMode(s.to_owned())),
+        }
+    }
+}
+
+impl FromPest for AccessMode {
+    fn from_pest(element: Pair<'_, Rule>) -> Result<Self, ParsingError>
+    where
+        Self: Sized,
+    {
+        match element.as_rule() {
+            Rule::ACCESS_MODE => Ok(AccessMode::from_str(element.as_span().as_str())?),
+            _ => Err(ParsingError::InvalidPestRule {
+                expected: Rule::ACCESS_MODE,
+                found: element.as_rule(),
+            }),
+        }
+    }
+}
+
+/// Splits a texture keyword (without its kind prefix) into its kind
+/// and dimension, e.g. `cube` or `multisampled_2d`.
+fn texture_kind_and_dimension(
+    keyword: &str,
+    single_kind: TextureKind,
+    multisampled_kind: TextureKind,
+) -> Result<(TextureKind, TextureDimension), InvalidTextureDimension> {
+    match keyword.strip_prefix("multisampled_") {
+        Some(dimension) => Ok((multisampled_kind, TextureDimension::from_str(dimension)?)),
+        None => Ok((single_kind, TextureDimension::from_str(keyword)?)),
+    }
+}
+
+impl FromPest for Texture {
+    fn from_pest(element: Pair<'_, Rule>) -> Result<Self, ParsingError>
+    where
+        Self: Sized,
+    {
+        match element.as_rule() {
+            Rule::TEXTURE => {
+                let mut kind = TextureKind::default();
+                let mut dimension = TextureDimension::default();
+                let mut sample_type = None;
+                let mut texel_format = String::new();
+                let mut access_mode = AccessMode::default();
+
+                for texture_element in element.into_inner() {
+                    let keyword = texture_element.as_span().as_str();
+
+                    match texture_element.as_rule() {
+                        Rule::SAMPLED_TEXTURE_KIND => {
+                            (kind, dimension) = texture_kind_and_dimension(
+                                keyword.trim_start_matches("texture_"),
+                                TextureKind::Sampled,
+                                TextureKind::Multisampled,
+                            )?;
+                        }
+                        Rule::DEPTH_TEXTURE_KIND => {
+                            (kind, dimension) = texture_kind_and_dimension(
+                                keyword.trim_start_matches("texture_depth_"),
+                                TextureKind::Depth,
+                                TextureKind::DepthMultisampled,
+                            )?;
+                        }
+                        Rule::STORAGE_TEXTURE_KIND => {
+                            kind = TextureKind::Storage;
+                            dimension = TextureDimension::from_str(
+                                keyword.trim_start_matches("texture_storage_"),
+                            )?;
+                        }
+                        Rule::EXTERNAL_TEXTURE_KIND => {
+                            kind = TextureKind::External;
+                        }
+                        Rule::PRIMITIVE => {
+                            sample_type = Some(Primitive::from_pest(texture_element)?);
+                        }
+                        Rule::TEXEL_FORMAT => {
+                            texel_format = keyword.to_owned();
+                        }
+                        Rule::ACCESS_MODE => {
+                            access_mode = AccessMode::from_pest(texture_element)?;
+                        }
+                        _ => {}
+                    }
+                }
+
+                Ok(match (kind, sample_type) {
+                    (TextureKind::Storage, _) => {
+                        Texture::storage(dimension, texel_format, access_mode)
+                    }
+                    (_, Some(sample_type)) => Texture::sampled(kind, dimension, sample_type),
+                    (_, None) => Texture::new(kind, dimension),
+                })
+            }
+            _ => Err(ParsingError::InvalidPestRule {
+                expected: Rule::TEXTURE,
+                found: element.as_rule(),
+            }),
+        }
+    }
+}
+
+impl FromPest for Sampler {
+    fn from_pest(element: Pair<'_, Rule>) -> Result<Self, ParsingError>
+    where
+        Self: Sized,
+    {
+        match element.as_rule() {
+            Rule::SAMPLER => match element.as_span().as_str() {
+                "sampler_comparison" => Ok(Sampler::Comparison),
+                _ => Ok(Sampler::Sampler),
+            },
+            _ => Err(ParsingError::InvalidPestRule {
+                expected: Rule::SAMPLER,
+                found: element.as_rule(),
+            }),
+        }
+    }
+}
+
+/// Resolves WGSL predeclared type aliases (e.g. `vec3f`, `vec4h`, `vec2i`, `mat4x4f`),
+/// which the grammar matches as plain path types, into their canonical types.
+/// Path types that are not predeclared aliases are returned unchanged.
+pub fn resolve_predeclared_alias(path: PathType) -> Type {
+    if path.module().is_some() || !path.generics().is_empty() {
+        return Type::Path(path);
+    }
+
+    predeclared_alias(path.name()).unwrap_or(Type::Path(path))
+}
+
+/// Returns the canonical type of predeclared alias `name`, where the last character
+/// is the component type (`f`, `h`, `i` or `u`; matrices only allow `f` and `h`).
+fn predeclared_alias(name: &str) -> Option<Type> {
+    let suffix = name.chars().last()?;
+    let name = &name[..name.len() - suffix.len_utf8()];
+
+    let component = match suffix {
+        'f' => Primitive::Float32,
+        'h' => Primitive::Float16,
+        'i' => Primitive::Sint32,
+        'u' => Primitive::Uint32,
+        _ => return None,
+    };
+
+    if let Some(dimension) = name.strip_prefix("vec") {
+        return Some(Type::Vector(Vector::new(
+            VectorDimension::from_str(dimension).ok()?,
+            component,
+        )));
+    }
+
+    let (columns, rows) = name.strip_prefix("mat")?.split_once('x')?;
+
+    if !matches!(component, Primitive::Float32 | Primitive::Float16) {
+        return None;
+    }
+
+    Some(Type::Matrix(Matrix::new(
+        VectorDimension::from_str(columns).ok()?,
+        VectorDimension::from_str(rows).ok()?,
+        component,
+    )))
+}
+
+impl FromPest for Array {
+    fn from_pest(element: Pair<'_, Rule>) -> Result<Self, ParsingError>
+    where
+        Self: Sized,
+    {
+        match element.as_rule() {
+            Rule::ARRAY => {
+                let mut ty = Type::default();
+                let mut size = None;
+
+                for array_element in element.into_inner() {
+                    match array_element.as_rule() {
+                        Rule::TYPE => {
+                            ty = Type::from_pest(array_element)?;
+                        }
+                        Rule::ARRAY_SIZE => {
+                            let size_str = array_element.as_span().as_str().trim();
+                            size = Some(size_str.trim_end_matches(',').trim_end().to_owned())
+                                .filter(|s| !s.is_empty());
+                        }
+                        _ => {}
+                    }
+                }
+
+                Ok(Array::new(ty, size))
+            }
+            _ => Err(ParsingError::InvalidPestRule {
+                expected: Rule::ARRAY,
+                found: element.as_rule(),
+            }),
+        }
+    }
+}
+
 /// Error for invalid vector dimensions during parsing.
 #[derive(Debug, Error)]
 #[error("Invalid vector dimension `{0}`; available are 2, 3, 4")]
@@ -150,6 +446,83 @@ impl FromPest for VectorDimension {
     }
 }
 
+/// Error for invalid address spaces during parsing.
+#[derive(Debug, Error)]
+#[error(
+    "Invalid address space `{0}`; available are function, private, workgroup, uniform, storage"
+)]
+pub struct InvalidAddressSpace(String);
+
+impl FromStr for AddressSpace {
+    type Err = InvalidAddressSpace;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use AddressSpace::*;
+
+        match s {
+            "function" => Ok(Function),
+            "private" => Ok(Private),
+            "workgroup" => Ok(Workgroup),
+            "uniform" => Ok(Uniform),
+            "storage" => Ok(Storage),
+            _ => Err(InvalidAddressSpace(s.to_owned())),
+        }
+    }
+}
+
+impl FromPest for AddressSpace {
+    fn from_pest(element: Pair<'_, Rule>) -> Result<Self, ParsingError>
+    where
+        Self: Sized,
+    {
+        match element.as_rule() {
+            Rule::STORAGE_CLASS | Rule::ADDRESS_SPACE | Rule::GLOBAL_VAR_ADDRESS_SPACE => {
+                Ok(AddressSpace::from_str(element.as_span().as_str())?)
+            }
+            _ => Err(ParsingError::InvalidPestRule {
+                expected: Rule::STORAGE_CLASS,
+                found: element.as_rule(),
+            }),
+        }
+    }
+}
+
+impl FromPest for Pointer {
+    fn from_pest(element: Pair<'_, Rule>) -> Result<Self, ParsingError>
+    where
+        Self: Sized,
+    {
+        match element.as_rule() {
+            Rule::POINTER => {
+                let mut address_space = AddressSpace::default();
+                let mut ty = Type::default();
+                let mut access_mode = None;
+
+                for pointer_element in element.into_inner() {
+                    match pointer_element.as_rule() {
+                        Rule::ADDRESS_SPACE => {
+                            address_space = AddressSpace::from_pest(pointer_element)?;
+                        }
+                        Rule::TYPE => {
+                            ty = Type::from_pest(pointer_element)?;
+                        }
+                        Rule::ACCESS_MODE => {
+                            access_mode = Some(AccessMode::from_pest(pointer_element)?);
+                        }
+                        _ => {}
+                    }
+                }
+
+                Ok(Pointer::new(address_space, ty, access_mode))
+            }
+            _ => Err(ParsingError::InvalidPestRule {
+                expected: Rule::POINTER,
+                found: element.as_rule(),
+            }),
+        }
+    }
+}
+
 impl FromPest for PathType {
     fn from_pest(element: Pair<'_, Rule>) -> Result<Self, ParsingError>
     where
@@ -159,6 +532,7 @@ impl FromPest for PathType {
             Rule::PATH_TYPE => {
                 let mut module = None;
                 let mut name = String::new();
+                let mut generics = vec![];
 
                 for path_type_element in element.into_inner() {
                     match path_type_element.as_rule() {
@@ -169,11 +543,17 @@ impl FromPest for PathType {
                         Rule::IDENT => {
                             name = path_type_element.as_span().as_str().to_owned();
                         }
+                        Rule::GENERIC_ARGS => {
+                            generics = path_type_element
+                                .into_inner()
+                                .map(Type::from_pest)
+                                .collect::<Result<_, _>>()?;
+                        }
                         _ => {}
                     }
                 }
 
-                Ok(PathType::new(module, name))
+                Ok(PathType::new(module, name, generics))
             }
             _ => Err(ParsingError::InvalidPestRule {
                 expected: Rule::PATH_TYPE,
@@ -3,3 +3,4 @@
 pub mod error;
 pub mod html;
 pub mod macros;
+pub mod path;
@@ -0,0 +1,36 @@
+//! Module for converting between module names and file system paths or URLs.
+
+use std::path::{Component, Path};
+
+/// Converts a module name (which may be an import path like `my_crate::lighting`)
+/// to a relative path usable both as a directory and a URL, e.g. `my_crate/lighting`.
+/// Characters which aren't allowed in file names on some platforms are replaced with `_`.
+pub fn module_dir(module_name: &str) -> String {
+    module_name
+        .split("::")
+        .map(|segment| {
+            segment
+                .chars()
+                .map(|c| match c {
+                    '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*' => '_',
+                    c => c,
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Converts the path of a shader file relative to the package root (e.g. `lighting/pbr.wesl`)
+/// to the name of its module, e.g. `lighting::pbr`.
+pub fn module_name(relative_path: &Path) -> String {
+    relative_path
+        .with_extension("")
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(segment) => segment.to_str(),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("::")
+}
@@ -195,3 +195,708 @@ fn test_registered_document_accessors() {
     assert!(registered.readme().is_none());
     assert!(!registered.favicon().is_empty());
 }
+
+#[test]
+fn test_document_register_links_same_module_alias() {
+    use wgsldoc::models::types::{ImportModule, Type, TypeKind};
+
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("test.wgsl"),
+        "alias Color = vec4<f32>;\nstruct Light { color: Color }",
+    )
+    .unwrap();
+
+    let registered = Document::open("test_project", temp_dir.path())
+        .unwrap()
+        .register();
+
+    let field = &registered.shaders()[0].structures[0].fields()[0];
+    let Type::Path(path) = field.field_type() else {
+        panic!("Expected path type");
+    };
+
+    assert_eq!(*path.import_module(), ImportModule::This);
+    assert_eq!(path.kind(), TypeKind::Alias);
+}
+
+#[test]
+fn test_registered_document_generates_entry_points() {
+    use wgsldoc::generator::TeraGenerator;
+
+    let temp_dir = TempDir::new().unwrap();
+    let out_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("test.wgsl"),
+        "@compute @workgroup_size(64)\nfn main(@builtin(global_invocation_id) id: vec3<u32>) {}\nfn helper() {}",
+    )
+    .unwrap();
+
+    let registered = Document::open("test_project", temp_dir.path())
+        .unwrap()
+        .register();
+
+    let mut generator = TeraGenerator::new(None);
+    registered.generate(&mut generator, out_dir.path()).unwrap();
+
+    let page = fs::read_to_string(out_dir.path().join("entry_points.html")).unwrap();
+    assert!(page.contains("fn.main.html"));
+    assert!(page.contains("@workgroup_size(64)"));
+    assert!(page.contains("<code>@builtin(global_invocation_id)</code>"));
+    assert!(!page.contains("helper"));
+}
+
+#[test]
+fn test_registered_document_generates_entry_point_io_attributes() {
+    use wgsldoc::generator::TeraGenerator;
+
+    let temp_dir = TempDir::new().unwrap();
+    let out_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("test.wgsl"),
+        "@fragment\nfn fs_main(@location(0) @interpolate(flat) id: u32) -> @location(1) vec4<f32> {}",
+    )
+    .unwrap();
+
+    let registered = Document::open("test_project", temp_dir.path())
+        .unwrap()
+        .register();
+
+    let mut generator = TeraGenerator::new(None);
+    registered.generate(&mut generator, out_dir.path()).unwrap();
+
+    let page = fs::read_to_string(out_dir.path().join("entry_points.html")).unwrap();
+    assert!(page.contains("<code>@location(0)</code>"));
+    assert!(page.contains("<code>@interpolate(flat)</code>"));
+    assert!(page.contains("<code>@location(1)</code>"));
+
+    let page = fs::read_to_string(out_dir.path().join("modules/test/fn.fs_main.html")).unwrap();
+    assert!(page.contains("<code>@location(0)</code>"));
+    assert!(page.contains("<code>@location(1)</code>"));
+}
+
+#[test]
+fn test_bind_group_layout_merges_modules() {
+    use wgsldoc::models::layout::BindGroupLayout;
+
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("common.wgsl"),
+        "@group(1) @binding(0) var<uniform> camera: mat4x4<f32>;\n\
+         fn project() -> mat4x4<f32> { return camera; }",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("main.wgsl"),
+        "#import common.wgsl as common\n\
+         @group(0) @binding(1) var<storage, read_write> counters: array<u32>;\n\
+         @group(0) @binding(0) var<storage> input: array<u32>;\n\
+         @vertex\nfn vs_main() -> @builtin(position) vec4<f32> { let m = common::project(); return m[0]; }\n\
+         @compute @workgroup_size(64)\nfn cs_main() { counters[0] = input[0]; }",
+    )
+    .unwrap();
+
+    let registered = Document::open("test_project", temp_dir.path())
+        .unwrap()
+        .register();
+    let layout = BindGroupLayout::new(registered.shaders());
+
+    assert_eq!(layout.groups().keys().copied().collect::<Vec<_>>(), [0, 1]);
+
+    let group_0 = &layout.groups()[&0];
+    assert_eq!(group_0[0].binding().name(), "input");
+    assert_eq!(group_0[1].binding().name(), "counters");
+    assert_eq!(group_0[1].entry_points()[0].name(), "cs_main");
+
+    let camera = &layout.groups()[&1][0];
+    assert_eq!(camera.module().module_name, "common");
+    assert_eq!(camera.entry_points().len(), 1);
+    assert_eq!(camera.entry_points()[0].module(), "main");
+    assert_eq!(camera.entry_points()[0].name(), "vs_main");
+}
+
+#[test]
+fn test_bind_group_layout_fully_qualified_references() {
+    use wgsldoc::models::layout::BindGroupLayout;
+
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("mesh_view_bindings.wgsl"),
+        "#define_import_path bevy_pbr::mesh_view_bindings\n\
+         @group(0) @binding(0) var<uniform> view: mat4x4<f32>;",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("main.wgsl"),
+        "#import bevy_pbr::mesh_view_bindings\n\
+         @vertex\nfn vs_main() -> @builtin(position) vec4<f32> {\n\
+             return bevy_pbr::mesh_view_bindings::view[0];\n\
+         }",
+    )
+    .unwrap();
+
+    let registered = Document::open("test_project", temp_dir.path())
+        .unwrap()
+        .register();
+    let layout = BindGroupLayout::new(registered.shaders());
+
+    let view = &layout.groups()[&0][0];
+    assert_eq!(view.entry_points().len(), 1);
+    assert_eq!(view.entry_points()[0].name(), "vs_main");
+}
+
+#[test]
+fn test_registered_document_binding_conflicts() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("common.wgsl"),
+        "struct Light { color: vec4<f32> }\n\
+         @group(0) @binding(0) var<uniform> light: Light;\n\
+         @group(0) @binding(1) var<uniform> camera: mat4x4<f32>;",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("main.wgsl"),
+        "#import common.wgsl as common\n\
+         @group(0) @binding(0) var<uniform> main_light: common::Light;\n\
+         @group(0) @binding(1) var albedo: texture_2d<f32>;\n\
+         @fragment\nfn fs_main() {}",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("unrelated.wgsl"),
+        "@group(0) @binding(1) var albedo_sampler: sampler;\n@compute @workgroup_size(1)\nfn cs_main() {}",
+    )
+    .unwrap();
+
+    let registered = Document::open("test_project", temp_dir.path())
+        .unwrap()
+        .register();
+    let conflicts = registered.binding_conflicts();
+
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!((conflicts[0].group(), conflicts[0].binding()), (0, 1));
+    assert_eq!(conflicts[0].entry_modules(), ["main"]);
+
+    let declarations = conflicts[0]
+        .declarations()
+        .iter()
+        .map(|d| d.to_string())
+        .collect::<Vec<_>>();
+    assert!(declarations.contains(&"main::albedo: texture_2d<f32>".to_string()));
+    assert!(declarations.contains(&"common::camera: mat4x4<f32>".to_string()));
+}
+
+#[test]
+fn test_document_register_builtin_imports() {
+    use wgsldoc::models::{
+        import::ItemKind,
+        types::{ImportModule, Type, TypeKind},
+    };
+
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("forward_io.wgsl"),
+        "#define_import_path bevy_pbr::forward_io\n\
+         struct VertexOutput { position: vec4f }\nalias Color = vec4f;\nfn helper() {}",
+    )
+    .unwrap();
+    fs::write(temp_dir.path().join("maths.wgsl"), "const PI = 3.14;").unwrap();
+    fs::write(
+        temp_dir.path().join("main.wgsl"),
+        "#import bevy_pbr::forward_io::{VertexOutput, Color}\n\
+         #import bevy_pbr::forward_io::helper\n\
+         #import bevy_render::maths::{PI}\n\
+         fn shade(in: VertexOutput) -> Color {}",
+    )
+    .unwrap();
+
+    let registered = Document::open("test_project", temp_dir.path())
+        .unwrap()
+        .register();
+    let main = registered
+        .shaders()
+        .iter()
+        .find(|shader| shader.module_name == "main")
+        .unwrap();
+
+    let forward_io = &main.imports[0];
+    assert!(forward_io.registered());
+    assert_eq!(forward_io.module_name(), "bevy_pbr::forward_io");
+    assert_eq!(forward_io.items()[0].kind(), Some(ItemKind::Struct));
+    assert_eq!(forward_io.items()[1].kind(), Some(ItemKind::Alias));
+
+    // A path ending with an item imports just that item
+    let helper = &main.imports[1];
+    assert!(helper.registered());
+    assert_eq!(helper.module_path(), Some("bevy_pbr::forward_io"));
+    assert_eq!(helper.items()[0].kind(), Some(ItemKind::Function));
+
+    // Modules outside of the document stay unregistered, even if a module is named after their last segment
+    assert!(!main.imports[2].registered());
+
+    let Type::Path(return_type) = main.functions[0].return_type().unwrap() else {
+        panic!("Expected path type");
+    };
+    assert_eq!(
+        *return_type.import_module(),
+        ImportModule::Named("forward_io".to_string())
+    );
+    assert_eq!(return_type.kind(), TypeKind::Alias);
+}
+
+#[test]
+fn test_document_register_define_import_path() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("lighting.wgsl"),
+        "#define_import_path my_crate::lighting\nstruct Light { color: vec3f }",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("other_lighting.wgsl"),
+        "#define_import_path other_crate::lighting\nstruct Light { color: vec4f }",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("main.wgsl"),
+        "#import my_crate::lighting::{Light}\n#import other_crate::lighting::Light as other",
+    )
+    .unwrap();
+
+    let registered = Document::open("test_project", temp_dir.path())
+        .unwrap()
+        .register();
+    let main = registered
+        .shaders()
+        .iter()
+        .find(|shader| shader.module_name == "main")
+        .unwrap();
+
+    assert!(main.imports[0].registered());
+    assert_eq!(main.imports[0].module_name(), "my_crate::lighting");
+
+    assert!(main.imports[1].registered());
+    assert_eq!(main.imports[1].module_name(), "other_crate::lighting");
+    assert_eq!(main.imports[1].module_path(), Some("other_crate::lighting"));
+    assert_eq!(main.imports[1].items()[0].name(), "Light");
+}
+
+#[test]
+fn test_registered_document_generates_nested_module_paths() {
+    use wgsldoc::generator::TeraGenerator;
+
+    let temp_dir = TempDir::new().unwrap();
+    let out_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("lighting.wgsl"),
+        "#define_import_path my_crate::lighting\nstruct Light { color: vec3f }",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("main.wgsl"),
+        "#import my_crate::lighting::Light\nfn shade(light: Light) {}",
+    )
+    .unwrap();
+
+    let registered = Document::open("test_project", temp_dir.path())
+        .unwrap()
+        .register();
+
+    let mut generator = TeraGenerator::new(None);
+    registered.generate(&mut generator, out_dir.path()).unwrap();
+
+    let module_dir = out_dir.path().join("modules/my_crate/lighting");
+    assert!(module_dir.join("index.html").is_file());
+    assert!(module_dir.join("struct.Light.html").is_file());
+    assert!(out_dir
+        .path()
+        .join("source/my_crate/lighting.html")
+        .is_file());
+
+    let modules = fs::read_to_string(out_dir.path().join("modules/index.html")).unwrap();
+    assert!(modules.contains("/modules/my_crate/lighting/index.html\">my_crate::lighting</a>"));
+    assert!(!modules.contains("/modules/my_crate::lighting/"));
+
+    let page = fs::read_to_string(out_dir.path().join("modules/main/fn.shade.html")).unwrap();
+    assert!(page.contains("/modules/my_crate/lighting/struct.Light.html'>Light</a>"));
+}
+
+#[test]
+fn test_registered_document_generates_alternative_conditions() {
+    use wgsldoc::generator::TeraGenerator;
+
+    let temp_dir = TempDir::new().unwrap();
+    let out_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("test.wgsl"),
+        "#ifdef X\nfn both() {}\nfn some() {}\n#else\nfn both() {}\n#ifdef Y\nfn some() {}\n#endif\n#endif",
+    )
+    .unwrap();
+
+    let registered = Document::open("test_project", temp_dir.path())
+        .unwrap()
+        .register();
+
+    let mut generator = TeraGenerator::new(None);
+    registered.generate(&mut generator, out_dir.path()).unwrap();
+
+    let page = fs::read_to_string(out_dir.path().join("modules/test/fn.both.html")).unwrap();
+    assert!(!page.contains("Available only when"));
+
+    let page = fs::read_to_string(out_dir.path().join("modules/test/fn.some.html")).unwrap();
+    assert!(page.contains(
+        "Available only when <code>X</code>, or when <code>!X</code> and <code>Y</code>"
+    ));
+}
+
+#[test]
+fn test_registered_document_links_fully_qualified_types() {
+    use wgsldoc::generator::TeraGenerator;
+    use wgsldoc::models::types::{ImportModule, Type};
+
+    let temp_dir = TempDir::new().unwrap();
+    let out_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("forward_io.wgsl"),
+        "#define_import_path bevy_pbr::forward_io\nstruct VertexOutput { position: vec4f }",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("main.wgsl"),
+        "#import bevy_pbr::forward_io\n\
+         @fragment\n\
+         fn fs_main(in: bevy_pbr::forward_io::VertexOutput) -> @location(0) vec4f {}",
+    )
+    .unwrap();
+
+    let registered = Document::open("test_project", temp_dir.path())
+        .unwrap()
+        .register();
+    let main = registered
+        .shaders()
+        .iter()
+        .find(|shader| shader.module_name == "main")
+        .unwrap();
+
+    let Type::Path(input) = main.functions[0].args()[0].argument_type() else {
+        panic!("Expected path type");
+    };
+    assert_eq!(
+        *input.import_module(),
+        ImportModule::Named("forward_io".to_string())
+    );
+
+    let mut generator = TeraGenerator::new(None);
+    registered.generate(&mut generator, out_dir.path()).unwrap();
+
+    let page = fs::read_to_string(out_dir.path().join("modules/main/fn.fs_main.html")).unwrap();
+    assert!(
+        page.contains("/modules/bevy_pbr/forward_io/struct.VertexOutput.html'>VertexOutput</a>")
+    );
+}
+
+#[test]
+fn test_registered_document_links_qualified_aliases() {
+    use wgsldoc::generator::TeraGenerator;
+    use wgsldoc::models::types::{Type, TypeKind};
+
+    let temp_dir = TempDir::new().unwrap();
+    let out_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("shapes.wgsl"),
+        "alias Radius = f32;\nstruct Circle { radius: Radius }",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("main.wgsl"),
+        "#import shapes.wgsl as shapes\n\
+         struct Ring { inner: shapes::Radius, outer: shapes::Circle }",
+    )
+    .unwrap();
+
+    let registered = Document::open("test_project", temp_dir.path())
+        .unwrap()
+        .register();
+    let main = registered
+        .shaders()
+        .iter()
+        .find(|shader| shader.module_name == "main")
+        .unwrap();
+
+    let fields = main.structures[0].fields();
+    let Type::Path(inner) = fields[0].field_type() else {
+        panic!("Expected path type");
+    };
+    assert_eq!(inner.kind(), TypeKind::Alias);
+    let Type::Path(outer) = fields[1].field_type() else {
+        panic!("Expected path type");
+    };
+    assert_eq!(outer.kind(), TypeKind::Struct);
+
+    let mut generator = TeraGenerator::new(None);
+    registered.generate(&mut generator, out_dir.path()).unwrap();
+
+    let page = fs::read_to_string(out_dir.path().join("modules/main/struct.Ring.html")).unwrap();
+    assert!(page.contains("/modules/shapes/alias.Radius.html'>Radius</a>"));
+    assert!(page.contains("/modules/shapes/struct.Circle.html'>Circle</a>"));
+}
+
+#[test]
+fn test_document_with_shader_defs() {
+    use wgsldoc::models::condition::{ShaderDefValue, ShaderDefs};
+    use wgsldoc::DocumentOptions;
+
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("lights.wgsl"),
+        "#ifdef SHADOWS\nfn shadow() {}\n#else\nfn no_shadow() {}\n#endif",
+    )
+    .unwrap();
+
+    let shader_defs = ShaderDefs::from([("SHADOWS".to_string(), ShaderDefValue::Bool(true))]);
+    let options = DocumentOptions {
+        shader_defs: Some(shader_defs),
+        ..Default::default()
+    };
+    let document = Document::open_with_options("test_project", temp_dir.path(), options).unwrap();
+
+    assert!(document.options().shader_defs.is_some());
+    assert_eq!(document.shaders()[0].functions.len(), 1);
+    assert_eq!(document.shaders()[0].functions[0].name(), "shadow");
+
+    let document = Document::open("test_project", temp_dir.path()).unwrap();
+    assert!(document.options().shader_defs.is_none());
+    assert_eq!(document.shaders()[0].functions.len(), 2);
+}
+
+#[test]
+fn test_document_with_recovery() {
+    use wgsldoc::DocumentOptions;
+
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("partial.wgsl"),
+        "fn valid() {}\n\nthis is not valid WGSL {{ }}\n\nstruct Point { x: f32 }",
+    )
+    .unwrap();
+
+    assert!(Document::open("test_project", temp_dir.path()).is_err());
+
+    let options = DocumentOptions {
+        recover: true,
+        ..Default::default()
+    };
+    let document = Document::open_with_options("test_project", temp_dir.path(), options).unwrap();
+    assert!(document.options().recover);
+    let shader = &document.shaders()[0];
+
+    assert_eq!(shader.functions.len(), 1);
+    assert_eq!(shader.structures.len(), 1);
+    assert_eq!(shader.skipped_items.len(), 1);
+    assert_eq!(shader.skipped_items[0].line(), 3);
+}
+
+#[test]
+fn test_document_register_overrides() {
+    use wgsldoc::models::function::FunctionModifier;
+
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("lighting.wgsl"),
+        "#define_import_path bevy_pbr::lighting\n\
+         virtual fn point_light(light_id: u32) -> vec3f { return vec3f(0.0); }",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("toon.wgsl"),
+        "#import bevy_pbr::lighting as lighting\n\
+         override fn lighting::point_light(light_id: u32) -> vec3f { return vec3f(1.0); }",
+    )
+    .unwrap();
+
+    let registered = Document::open("test_project", temp_dir.path())
+        .unwrap()
+        .register();
+    let shader = |name: &str| {
+        registered
+            .shaders()
+            .iter()
+            .find(|shader| shader.module_name == name)
+            .unwrap()
+    };
+
+    assert_eq!(
+        shader("bevy_pbr::lighting").functions[0].modifier(),
+        Some(&FunctionModifier::Virtual {
+            overridden_by: vec!["toon".to_string()],
+        })
+    );
+    assert_eq!(
+        shader("toon").functions[0].modifier(),
+        Some(&FunctionModifier::Override {
+            module_path: "lighting".to_string(),
+            module: Some("bevy_pbr::lighting".to_string()),
+        })
+    );
+}
+
+#[test]
+fn test_document_wesl_modules() {
+    use wgsldoc::models::import::ItemKind;
+
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("lighting.wesl"),
+        "struct Light { color: vec3f }\nfn pbr(l: Light) -> vec3f { return l.color; }",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("main.wesl"),
+        "import package::lighting::{Light, pbr};\n\
+         import super::lighting;\n\
+         import package::lighting::pbr;\n\
+         fn shade(l: Light) -> vec3f { return lighting::pbr(l); }",
+    )
+    .unwrap();
+
+    let registered = Document::open("test_project", temp_dir.path())
+        .unwrap()
+        .register();
+    assert_eq!(registered.shaders().len(), 2);
+
+    let main = registered
+        .shaders()
+        .iter()
+        .find(|shader| shader.module_name == "main")
+        .unwrap();
+
+    assert!(main.imports.iter().all(|import| import.registered()));
+    assert!(main
+        .imports
+        .iter()
+        .all(|import| import.module_name() == "lighting"));
+    assert_eq!(main.imports[0].items()[0].kind(), Some(ItemKind::Struct));
+    assert_eq!(main.imports[2].module_path(), Some("package::lighting"));
+    assert_eq!(main.imports[2].items()[0].kind(), Some(ItemKind::Function));
+}
+
+#[test]
+fn test_document_wesl_super_imports() {
+    use wgsldoc::models::import::ItemKind;
+
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("lighting.wgsl"),
+        "#define_import_path my_crate::lighting\nstruct Light { color: vec3f }",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("util.wgsl"),
+        "#define_import_path my_crate::util\nfn helper() {}",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("shadows.wesl"),
+        "#define_import_path my_crate::lighting::shadows\n\
+         import super::Light;\n\
+         import super::super::util;\n\
+         import super::super::super::super::lighting;\n\
+         fn shadow(l: Light) {}",
+    )
+    .unwrap();
+
+    let registered = Document::open("test_project", temp_dir.path())
+        .unwrap()
+        .register();
+    let shadows = registered
+        .shaders()
+        .iter()
+        .find(|shader| shader.module_name == "my_crate::lighting::shadows")
+        .unwrap();
+
+    // `super` refers to the parent of the importing module
+    assert!(shadows.imports[0].registered());
+    assert_eq!(shadows.imports[0].module_name(), "my_crate::lighting");
+    assert_eq!(shadows.imports[0].module_path(), Some("super"));
+    assert_eq!(shadows.imports[0].items()[0].kind(), Some(ItemKind::Struct));
+
+    assert!(shadows.imports[1].registered());
+    assert_eq!(shadows.imports[1].module_name(), "my_crate::util");
+
+    // Paths beyond the package root don't refer to any module
+    assert!(!shadows.imports[2].registered());
+}
+
+#[test]
+fn test_document_nested_wesl_modules() {
+    use wgsldoc::generator::TeraGenerator;
+    use wgsldoc::models::import::ItemKind;
+
+    let temp_dir = TempDir::new().unwrap();
+    let out_dir = TempDir::new().unwrap();
+    let lighting_dir = temp_dir.path().join("lighting");
+    fs::create_dir_all(&lighting_dir).unwrap();
+    fs::create_dir_all(temp_dir.path().join(".hidden")).unwrap();
+
+    fs::write(
+        temp_dir.path().join("main.wesl"),
+        "import package::lighting::pbr::{Pbr};\nfn shade(p: Pbr) {}",
+    )
+    .unwrap();
+    fs::write(
+        lighting_dir.join("pbr.wesl"),
+        "import super::common::{Light};\nstruct Pbr { light: Light }",
+    )
+    .unwrap();
+    fs::write(
+        lighting_dir.join("common.wesl"),
+        "struct Light { color: vec3f }",
+    )
+    .unwrap();
+    fs::write(lighting_dir.join("README.md"), "# Not the package README").unwrap();
+    fs::write(
+        temp_dir.path().join(".hidden/ignored.wgsl"),
+        "fn ignored() {}",
+    )
+    .unwrap();
+
+    let document = Document::open("test_project", temp_dir.path()).unwrap();
+    assert!(document.readme().is_none());
+
+    let mut module_names = document
+        .shaders()
+        .iter()
+        .map(|shader| shader.module_name.as_str())
+        .collect::<Vec<_>>();
+    module_names.sort();
+    assert_eq!(module_names, ["lighting::common", "lighting::pbr", "main"]);
+
+    let registered = document.register();
+    let find = |module_name: &str| {
+        registered
+            .shaders()
+            .iter()
+            .find(|shader| shader.module_name == module_name)
+            .unwrap()
+    };
+
+    let main = find("main");
+    assert!(main.imports[0].registered());
+    assert_eq!(main.imports[0].module_name(), "lighting::pbr");
+    assert_eq!(main.imports[0].items()[0].kind(), Some(ItemKind::Struct));
+
+    let pbr = find("lighting::pbr");
+    assert!(pbr.imports[0].registered());
+    assert_eq!(pbr.imports[0].module_name(), "lighting::common");
+
+    let mut generator = TeraGenerator::new(None);
+    registered.generate(&mut generator, out_dir.path()).unwrap();
+
+    assert!(out_dir
+        .path()
+        .join("modules/lighting/pbr/struct.Pbr.html")
+        .is_file());
+    let page = fs::read_to_string(out_dir.path().join("modules/main/fn.shade.html")).unwrap();
+    assert!(page.contains("/modules/lighting/pbr/struct.Pbr.html'>Pbr</a>"));
+}
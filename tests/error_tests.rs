@@ -165,3 +165,52 @@ fn test_document_with_empty_paths() {
     let result = Document::new("test", &[] as &[PathBuf]);
     assert!(result.is_ok());
 }
+
+#[test]
+fn test_parse_unbalanced_conditional_blocks() {
+    let missing_endif = "#ifdef SKINNED\nfn skin() {}\n";
+    assert!(WgslParser::parse("test", missing_endif).is_err());
+
+    let unexpected_endif = "fn skin() {}\n#endif\n";
+    assert!(WgslParser::parse("test", unexpected_endif).is_err());
+
+    let invalid_operator = "#if MAX_LIGHTS => 4\nfn skin() {}\n#endif\n";
+    assert!(WgslParser::parse("test", invalid_operator).is_err());
+}
+
+#[test]
+fn test_parse_override_id_out_of_range() {
+    let result = WgslParser::parse("test", "@id(70000) override A: u32;");
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_parse_member_attribute_out_of_range() {
+    let shader_code = "struct S {\n    @align(4294967296) x: f32,\n}";
+    let result = WgslParser::parse("test", shader_code);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_preprocess_undefined_shader_def_substitution() {
+    use wgsldoc::models::condition::{ShaderDefValue, ShaderDefs};
+    use wgsldoc::parser::preprocessor::{Preprocessor, PreprocessorError};
+
+    let shader_defs = ShaderDefs::from([("MAX_LIGHTS".to_string(), ShaderDefValue::Int(4))]);
+    let preprocessor = Preprocessor::with_shader_defs(shader_defs);
+    let result = preprocessor.process("const A = #{MAX_LIGHTS};\nconst B = #{MAX_JOINTS};\n");
+
+    assert!(matches!(
+        result,
+        Err(PreprocessorError::UndefinedShaderDef { ref name, line: 2 }) if name == "MAX_JOINTS"
+    ));
+}
+
+#[test]
+fn test_parse_module_scope_function_variable() {
+    let result = WgslParser::parse("test", "var<function> x: f32;");
+    assert!(result.is_err());
+
+    let result = WgslParser::parse("test", "fn f(p: ptr<function, f32>) {}");
+    assert!(result.is_ok());
+}
@@ -2,8 +2,8 @@ use std::path::PathBuf;
 use wgsldoc::models::{
     function::{Arg, Function, FunctionType},
     import::Import,
-    structure::{Field, Structure},
-    types::{PathType, Primitive, Type, Vector, VectorDimension},
+    structure::{Field, MemberAttribute, Structure},
+    types::{AddressSpace, PathType, Pointer, Primitive, Type, Vector, VectorDimension},
     ComponentInfo,
 };
 
@@ -18,8 +18,18 @@ fn test_structure_new() {
 #[test]
 fn test_structure_with_fields() {
     let fields = vec![
-        Field::new(None, "x".to_string(), Type::Primitive(Primitive::Float32)),
-        Field::new(None, "y".to_string(), Type::Primitive(Primitive::Float32)),
+        Field::new(
+            None,
+            vec![],
+            "x".to_string(),
+            Type::Primitive(Primitive::Float32),
+        ),
+        Field::new(
+            None,
+            vec![],
+            "y".to_string(),
+            Type::Primitive(Primitive::Float32),
+        ),
     ];
 
     let structure = Structure::new(None, "Point".to_string(), fields);
@@ -41,6 +51,21 @@ fn test_structure_info_plain_text() {
     assert!(info.summary.is_some());
 }
 
+#[test]
+fn test_structure_info_plain_text_truncates_on_char_boundary() {
+    // The limit falls in the middle of the two-byte `é`
+    let docs = format!("{}{}", "a".repeat(255), "é".repeat(10));
+    let structure = Structure::new(Some(docs), "Point".to_string(), vec![]);
+
+    let info = structure.info_plain_text();
+    assert_eq!(info.summary, Some(format!("{}é...", "a".repeat(255))));
+
+    assert_eq!(
+        ComponentInfo::truncate_summary("Short summary".to_string()),
+        "Short summary"
+    );
+}
+
 #[test]
 fn test_structure_info_rich_text() {
     let structure = Structure::new(
@@ -58,26 +83,52 @@ fn test_structure_info_rich_text() {
 fn test_field_new() {
     let field = Field::new(
         Some("X coordinate".to_string()),
+        vec![],
         "x".to_string(),
         Type::Primitive(Primitive::Float32),
     );
 
     assert_eq!(field.name(), "x");
     assert_eq!(field.docs(), Some("X coordinate"));
+    assert!(field.attributes().is_empty());
+}
+
+#[test]
+fn test_member_attribute_display() {
+    assert_eq!(MemberAttribute::Align(16).to_string(), "@align(16)");
+    assert_eq!(
+        MemberAttribute::Builtin("position".to_string()).to_string(),
+        "@builtin(position)"
+    );
+    assert_eq!(
+        MemberAttribute::Interpolate {
+            ty: "perspective".to_string(),
+            sampling: Some("centroid".to_string()),
+        }
+        .to_string(),
+        "@interpolate(perspective, centroid)"
+    );
+    assert_eq!(MemberAttribute::Invariant.to_string(), "@invariant");
 }
 
 #[test]
 fn test_function_new() {
     let function = Function::new(
         Some("Adds two numbers".to_string()),
+        None,
+        vec![],
+        None,
         "add".to_string(),
         vec![],
         Some(Type::Primitive(Primitive::Sint32)),
+        vec![],
+        vec![],
     );
 
     assert_eq!(function.name(), "add");
     assert_eq!(function.docs(), Some("Adds two numbers"));
     assert!(function.return_type().is_some());
+    assert!(!function.is_entry_point());
 }
 
 #[test]
@@ -85,21 +136,28 @@ fn test_function_with_args() {
     let args = vec![
         Arg::new(
             None,
+            vec![],
             "a".to_string(),
             FunctionType::Primitive(Primitive::Sint32),
         ),
         Arg::new(
             None,
+            vec![],
             "b".to_string(),
             FunctionType::Primitive(Primitive::Sint32),
         ),
     ];
 
     let function = Function::new(
+        None,
+        None,
+        vec![],
         None,
         "add".to_string(),
         args,
         Some(Type::Primitive(Primitive::Sint32)),
+        vec![],
+        vec![],
     );
 
     assert_eq!(function.args().len(), 2);
@@ -111,9 +169,14 @@ fn test_function_with_args() {
 fn test_function_info_plain_text() {
     let function = Function::new(
         Some("Calculates distance".to_string()),
+        None,
+        vec![],
+        None,
         "distance".to_string(),
         vec![],
         Some(Type::Primitive(Primitive::Float32)),
+        vec![],
+        vec![],
     );
 
     let info = function.info_plain_text();
@@ -125,9 +188,14 @@ fn test_function_info_plain_text() {
 fn test_function_info_rich_text() {
     let function = Function::new(
         Some("A **fast** function".to_string()),
+        None,
+        vec![],
+        None,
         "compute".to_string(),
         vec![],
         None,
+        vec![],
+        vec![],
     );
 
     let info = function.info_rich_text();
@@ -139,6 +207,7 @@ fn test_function_info_rich_text() {
 fn test_arg_new() {
     let arg = Arg::new(
         Some("Input value".to_string()),
+        vec![],
         "x".to_string(),
         FunctionType::Primitive(Primitive::Float32),
     );
@@ -151,6 +220,7 @@ fn test_arg_new() {
 fn test_arg_with_vector_type() {
     let arg = Arg::new(
         None,
+        vec![],
         "position".to_string(),
         FunctionType::Vector(Vector::new(VectorDimension::D3, Primitive::Float32)),
     );
@@ -162,10 +232,12 @@ fn test_arg_with_vector_type() {
 fn test_arg_with_path_type() {
     let arg = Arg::new(
         None,
+        vec![],
         "camera".to_string(),
         FunctionType::Path(PathType::new(
             Some("Utils".to_string()),
             "Camera".to_string(),
+            vec![],
         )),
     );
 
@@ -176,8 +248,13 @@ fn test_arg_with_path_type() {
 fn test_arg_with_function_pointer() {
     let arg = Arg::new(
         None,
+        vec![],
         "ptr".to_string(),
-        FunctionType::FunctionPointer(Type::Primitive(Primitive::Float32)),
+        FunctionType::Pointer(Pointer::new(
+            AddressSpace::Function,
+            Type::Primitive(Primitive::Float32),
+            None,
+        )),
     );
 
     assert_eq!(arg.name(), "ptr");
@@ -196,6 +273,31 @@ fn test_import_new() {
     assert!(!import.registered());
 }
 
+#[test]
+fn test_import_builtin() {
+    let import = Import::builtin(
+        None,
+        "bevy_pbr::forward_io".to_string(),
+        None,
+        vec!["VertexOutput".to_string()],
+    );
+
+    assert_eq!(import.name(), "forward_io");
+    assert_eq!(import.module_path(), Some("bevy_pbr::forward_io"));
+    assert_eq!(import.items()[0].name(), "VertexOutput");
+    assert!(import.item("VertexOutput").is_some());
+    assert!(!import.registered());
+
+    let aliased = Import::builtin(
+        None,
+        "bevy_pbr::mesh_view_bindings".to_string(),
+        Some("view".to_string()),
+        vec![],
+    );
+    assert_eq!(aliased.name(), "view");
+    assert!(aliased.items().is_empty());
+}
+
 #[test]
 fn test_import_info_plain_text() {
     let import = Import::new(
@@ -233,3 +335,36 @@ fn test_component_info_without_summary() {
     assert_eq!(info.name, "TestComponent");
     assert!(info.summary.is_none());
 }
+
+#[test]
+fn test_condition_evaluate() {
+    use wgsldoc::models::condition::{ComparisonOp, Condition, ShaderDefValue, ShaderDefs};
+    use wgsldoc::parser::preprocessor::parse_shader_def;
+
+    let shader_defs = ["SHADOWS", "SKINNED=false", "MAX_LIGHTS=16", "LAYERS=4u"]
+        .into_iter()
+        .map(|def| parse_shader_def(def).unwrap())
+        .collect::<ShaderDefs>();
+
+    assert_eq!(shader_defs["SHADOWS"], ShaderDefValue::Bool(true));
+    assert_eq!(shader_defs["LAYERS"], ShaderDefValue::UInt(4));
+    assert!(parse_shader_def("MAX_LIGHTS=many").is_err());
+
+    let defined = Condition::Defined("SHADOWS".to_string());
+    assert_eq!(defined.evaluate(&shader_defs), Some(true));
+    assert_eq!(defined.negated().evaluate(&shader_defs), Some(false));
+    assert_eq!(
+        Condition::Defined("SKINNED".to_string()).evaluate(&shader_defs),
+        Some(false)
+    );
+
+    let comparison = Condition::Comparison {
+        name: "MAX_LIGHTS".to_string(),
+        op: ComparisonOp::Greater,
+        value: "8".to_string(),
+    };
+    assert_eq!(comparison.evaluate(&shader_defs), Some(true));
+    assert_eq!(comparison.negated().to_string(), "MAX_LIGHTS <= 8");
+    assert_eq!(comparison.negated().evaluate(&shader_defs), Some(false));
+    assert_eq!(comparison.evaluate(&ShaderDefs::new()), None);
+}
@@ -178,6 +178,505 @@ fn test() {}
         .contains("global module documentation"));
 }
 
+#[test]
+fn test_parse_docs_keep_blank_lines() {
+    let shader_code = r#"
+/// Summary line.
+///
+/// Details here.
+fn test() {}
+"#;
+
+    let result = WgslParser::parse("test", shader_code);
+    assert!(result.is_ok());
+    let shader = result.unwrap();
+    assert_eq!(
+        shader.functions[0].docs(),
+        Some("Summary line.\n\nDetails here.")
+    );
+}
+
+#[test]
+fn test_parse_block_docs() {
+    let shader_code = r#"
+/*!
+ * Module docs in a block.
+ */
+
+/**
+ * Summary line.
+ *
+ * Details here.
+ */
+struct Light {
+    /** Light intensity */
+    intensity: f32,
+}
+
+/** Single-line docs. */
+fn test() {}
+"#;
+
+    let result = WgslParser::parse("test", shader_code);
+    assert!(result.is_ok());
+    let shader = result.unwrap();
+    assert_eq!(
+        shader.global_docs.as_deref(),
+        Some("Module docs in a block.")
+    );
+    assert_eq!(
+        shader.structures[0].docs(),
+        Some("Summary line.\n\nDetails here.")
+    );
+    assert_eq!(
+        shader.structures[0].fields()[0].docs(),
+        Some("Light intensity")
+    );
+    assert_eq!(shader.functions[0].docs(), Some("Single-line docs."));
+}
+
+#[test]
+fn test_parse_nested_block_comments() {
+    let shader_code = r#"
+/* A regular /* nested */ block comment */
+/**/
+struct Light {
+    /* before field */ intensity: f32,
+}
+
+fn test(/* arg comment */ x: f32) {}
+"#;
+
+    let result = WgslParser::parse("test", shader_code);
+    assert!(result.is_ok());
+    let shader = result.unwrap();
+    assert_eq!(shader.structures.len(), 1);
+    assert!(shader.structures[0].docs().is_none());
+    assert_eq!(shader.functions[0].args().len(), 1);
+}
+
+#[test]
+fn test_parse_member_attributes() {
+    use wgsldoc::models::structure::MemberAttribute;
+
+    let shader_code = r#"
+struct VertexOutput {
+    @builtin(position) @invariant clip_position: vec4<f32>,
+    @location(0) @interpolate(flat) instance: u32,
+    @location(1) @interpolate(perspective, centroid) uv: vec2<f32>,
+    @align(16) @size(32) color: vec3<f32>,
+    @align(0x10u) @location(2u) normal: vec3<f32>,
+}
+"#;
+
+    let result = WgslParser::parse("test", shader_code);
+    assert!(result.is_ok());
+    let shader = result.unwrap();
+    let fields = shader.structures[0].fields();
+
+    assert_eq!(fields[0].builtin(), Some("position"));
+    assert!(fields[0].attributes().contains(&MemberAttribute::Invariant));
+
+    assert_eq!(fields[1].location(), Some(0));
+    assert_eq!(
+        fields[1].attributes()[1],
+        MemberAttribute::Interpolate {
+            ty: "flat".to_string(),
+            sampling: None,
+        }
+    );
+
+    assert_eq!(fields[2].location(), Some(1));
+    assert_eq!(
+        fields[3].attributes(),
+        &[MemberAttribute::Align(16), MemberAttribute::Size(32)]
+    );
+    assert_eq!(
+        fields[4].attributes(),
+        &[MemberAttribute::Align(16), MemberAttribute::Location(2)]
+    );
+}
+
+#[test]
+fn test_parse_entry_points() {
+    use wgsldoc::models::function::ShaderStage;
+
+    let shader_code = r#"
+@vertex
+fn vs_main() -> @builtin(position) vec4<f32> {}
+
+@compute @workgroup_size(8, 8)
+fn cs_main() {}
+
+@workgroup_size(BLOCK_SIZE, (BLOCK_SIZE / 2), 1) @compute
+fn cs_reduce() {}
+
+fn helper() {}
+"#;
+
+    let result = WgslParser::parse("test", shader_code);
+    assert!(result.is_ok());
+    let shader = result.unwrap();
+
+    assert_eq!(shader.functions[0].stage(), Some(ShaderStage::Vertex));
+    assert!(shader.functions[0].workgroup_size().is_empty());
+
+    assert_eq!(shader.functions[1].stage(), Some(ShaderStage::Compute));
+    assert_eq!(shader.functions[1].workgroup_size(), &["8", "8"]);
+
+    assert_eq!(shader.functions[2].stage(), Some(ShaderStage::Compute));
+    assert_eq!(
+        shader.functions[2].workgroup_size(),
+        &["BLOCK_SIZE", "(BLOCK_SIZE / 2)", "1"]
+    );
+
+    assert!(!shader.functions[3].is_entry_point());
+}
+
+#[test]
+fn test_parse_entry_point_io_attributes() {
+    use wgsldoc::models::structure::MemberAttribute;
+
+    let shader_code = r#"
+@fragment
+fn fs_main(
+    @builtin(position) position: vec4<f32>,
+    @location(0) @interpolate(flat) id: u32,
+    uv: vec2<f32>,
+) -> @location(0) vec4<f32> {}
+
+@vertex
+fn vs_main() -> @builtin(position) @invariant vec4<f32> {}
+"#;
+
+    let shader = WgslParser::parse("test", shader_code).unwrap();
+    let args = shader.functions[0].args();
+
+    assert_eq!(args[0].builtin(), Some("position"));
+    assert_eq!(args[1].location(), Some(0));
+    assert_eq!(
+        args[1].attributes()[1],
+        MemberAttribute::Interpolate {
+            ty: "flat".to_string(),
+            sampling: None,
+        }
+    );
+    assert!(args[2].attributes().is_empty());
+    assert_eq!(
+        shader.functions[0].return_attributes(),
+        &[MemberAttribute::Location(0)]
+    );
+    assert_eq!(
+        shader.functions[1].return_attributes(),
+        &[
+            MemberAttribute::Builtin("position".to_string()),
+            MemberAttribute::Invariant
+        ]
+    );
+}
+
+#[test]
+fn test_parse_overrides() {
+    let shader_code = r#"
+/// Workgroup size along X
+@id(0) override BLOCK_SIZE: u32 = 64;
+override GAIN: f32;
+override SCALE = 2.0;
+"#;
+
+    let result = WgslParser::parse("test", shader_code);
+    assert!(result.is_ok());
+    let shader = result.unwrap();
+    assert_eq!(shader.overrides.len(), 3);
+
+    assert_eq!(shader.overrides[0].name(), "BLOCK_SIZE");
+    assert_eq!(shader.overrides[0].id(), Some(0));
+    assert_eq!(shader.overrides[0].value(), Some("64"));
+    assert!(shader.overrides[0].docs().is_some());
+
+    assert_eq!(shader.overrides[1].id(), None);
+    assert!(shader.overrides[1].override_type().is_some());
+    assert!(shader.overrides[1].value().is_none());
+
+    assert!(shader.overrides[2].override_type().is_none());
+}
+
+#[test]
+fn test_parse_override_id_literals() {
+    let shader_code =
+        "@id(7u) override A: u32;\n@id(0x10) override B: u32;\n@id( 3i ) override C: u32;";
+    let shader = WgslParser::parse("test", shader_code).unwrap();
+
+    let ids = shader.overrides.iter().map(|o| o.id()).collect::<Vec<_>>();
+    assert_eq!(ids, [Some(7), Some(16), Some(3)]);
+}
+
+#[test]
+fn test_parse_alias() {
+    let shader_code = r#"
+/// RGBA color
+alias Color = vec4<f32>;
+"#;
+
+    let result = WgslParser::parse("test", shader_code);
+    assert!(result.is_ok());
+    let shader = result.unwrap();
+    assert_eq!(shader.aliases.len(), 1);
+    assert_eq!(shader.aliases[0].name(), "Color");
+    assert!(shader.aliases[0].docs().unwrap().contains("RGBA"));
+}
+
+#[test]
+fn test_parse_directives() {
+    use wgsldoc::models::directive::DiagnosticSeverity;
+
+    let shader_code = r#"
+enable f16;
+enable clip_distances;
+requires readonly_and_readwrite_storage_textures;
+diagnostic(off, derivative_uniformity);
+"#;
+
+    let result = WgslParser::parse("test", shader_code);
+    assert!(result.is_ok());
+    let shader = result.unwrap();
+    assert_eq!(shader.extensions(), vec!["f16", "clip_distances"]);
+    assert_eq!(
+        shader.language_features(),
+        vec!["readonly_and_readwrite_storage_textures"]
+    );
+
+    let diagnostics = shader.diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(*diagnostics[0].severity(), DiagnosticSeverity::Off);
+    assert_eq!(diagnostics[0].rule(), "derivative_uniformity");
+}
+
+#[test]
+fn test_parse_global_vars() {
+    use wgsldoc::models::types::AddressSpace;
+
+    let shader_code = r#"
+/// Random seed of the invocation
+var<private> seed: u32 = 0u;
+var<workgroup> sums: Sums;
+@group(0) @binding(0) var<uniform> camera: Camera;
+"#;
+
+    let result = WgslParser::parse("test", shader_code);
+    assert!(result.is_ok());
+    let shader = result.unwrap();
+    assert_eq!(shader.bindings.len(), 1);
+    assert_eq!(shader.global_vars.len(), 2);
+
+    assert_eq!(shader.global_vars[0].name(), "seed");
+    assert_eq!(shader.global_vars[0].address_space(), AddressSpace::Private);
+    assert_eq!(shader.global_vars[0].value(), Some("0u"));
+    assert!(shader.global_vars[0].docs().is_some());
+
+    assert_eq!(
+        shader.global_vars[1].address_space(),
+        AddressSpace::Workgroup
+    );
+    assert!(shader.global_vars[1].value().is_none());
+}
+
+#[test]
+fn test_parse_array_types() {
+    use wgsldoc::models::types::Type;
+
+    let shader_code = r#"
+struct Lights {
+    items: array<Light, MAX_LIGHTS>,
+    grid: array<array<f32, 4>, 4>,
+}
+
+var<workgroup> tile: array<f32, 256>;
+@group(0) @binding(0) var<storage> lights: array<Light>;
+"#;
+
+    let result = WgslParser::parse("test", shader_code);
+    assert!(result.is_ok());
+    let shader = result.unwrap();
+
+    let Type::Array(items) = shader.structures[0].fields()[0].field_type() else {
+        panic!("Expected array type");
+    };
+    assert_eq!(items.size(), Some("MAX_LIGHTS"));
+    assert!(matches!(items.element_type(), Type::Path(p) if p.name() == "Light"));
+
+    let Type::Array(grid) = shader.structures[0].fields()[1].field_type() else {
+        panic!("Expected array type");
+    };
+    assert!(matches!(grid.element_type(), Type::Array(inner) if inner.size() == Some("4")));
+
+    assert!(matches!(
+        shader.global_vars[0].var_type(),
+        Some(Type::Array(tile)) if tile.size() == Some("256")
+    ));
+    assert!(matches!(
+        shader.bindings[0].binding_type(),
+        Type::Array(lights) if lights.is_runtime_sized()
+    ));
+}
+
+#[test]
+fn test_parse_matrix_types() {
+    use wgsldoc::models::{
+        function::FunctionType,
+        types::{Type, VectorDimension},
+    };
+
+    let shader_code = r#"
+struct Camera {
+    view: mat4x4<f32>,
+    normal: mat3x3f,
+}
+
+fn project(transform: mat4x3<f32>) -> vec4<f32> {}
+"#;
+
+    let result = WgslParser::parse("test", shader_code);
+    assert!(result.is_ok());
+    let shader = result.unwrap();
+
+    let Type::Matrix(view) = shader.structures[0].fields()[0].field_type() else {
+        panic!("Expected matrix type");
+    };
+    assert!(matches!(view.columns(), VectorDimension::D4));
+    assert!(matches!(view.rows(), VectorDimension::D4));
+
+    // Predeclared alias `mat3x3f` resolves to `mat3x3<f32>`
+    let Type::Matrix(normal) = shader.structures[0].fields()[1].field_type() else {
+        panic!("Expected matrix type");
+    };
+    assert_eq!(format!("{}", normal), "mat3x3&lt;f32&gt;");
+
+    let FunctionType::Matrix(transform) = shader.functions[0].args()[0].argument_type() else {
+        panic!("Expected matrix type");
+    };
+    assert!(matches!(transform.rows(), VectorDimension::D3));
+}
+
+#[test]
+fn test_parse_binding_address_space_and_access_mode() {
+    use wgsldoc::models::types::{AccessMode, AddressSpace};
+
+    let shader_code = r#"
+@group(0) @binding(0) var<uniform> camera: Camera;
+@group(0) @binding(1) var<storage> lights: array<Light>;
+@group(0) @binding(2) var<storage, read_write> particles: array<Particle>;
+@group(0) @binding(3) var albedo: texture_2d<f32>;
+"#;
+
+    let result = WgslParser::parse("test", shader_code);
+    assert!(result.is_ok());
+    let shader = result.unwrap();
+
+    let camera = &shader.bindings[0];
+    assert_eq!(camera.address_space(), Some(AddressSpace::Uniform));
+    assert_eq!(camera.access_mode(), None);
+    assert_eq!(camera.effective_access_mode(), Some(AccessMode::Read));
+
+    let lights = &shader.bindings[1];
+    assert_eq!(lights.address_space(), Some(AddressSpace::Storage));
+    assert_eq!(lights.effective_access_mode(), Some(AccessMode::Read));
+
+    let particles = &shader.bindings[2];
+    assert_eq!(particles.access_mode(), Some(AccessMode::ReadWrite));
+    assert_eq!(
+        particles.effective_access_mode(),
+        Some(AccessMode::ReadWrite)
+    );
+
+    let albedo = &shader.bindings[3];
+    assert_eq!(albedo.address_space(), None);
+    assert_eq!(albedo.effective_access_mode(), None);
+}
+
+#[test]
+fn test_parse_texture_bindings() {
+    use wgsldoc::models::types::{AccessMode, Sampler, TextureDimension, TextureKind, Type};
+
+    let shader_code = r#"
+@group(0) @binding(0) var albedo: texture_2d<f32>;
+@group(0) @binding(1) var albedo_sampler: sampler;
+@group(0) @binding(2) var shadow: texture_depth_cube;
+@group(0) @binding(3) var shadow_sampler: sampler_comparison;
+@group(0) @binding(4) var output: texture_storage_2d<rgba8unorm, write>;
+"#;
+
+    let result = WgslParser::parse("test", shader_code);
+    assert!(result.is_ok());
+    let shader = result.unwrap();
+    assert_eq!(shader.bindings.len(), 5);
+
+    let Type::Texture(albedo) = shader.bindings[0].binding_type() else {
+        panic!("Expected texture type");
+    };
+    assert_eq!(albedo.kind(), TextureKind::Sampled);
+    assert_eq!(albedo.dimension(), TextureDimension::D2);
+    assert!(albedo.sample_type().is_some());
+
+    assert!(matches!(
+        shader.bindings[1].binding_type(),
+        Type::Sampler(Sampler::Sampler)
+    ));
+
+    let Type::Texture(shadow) = shader.bindings[2].binding_type() else {
+        panic!("Expected texture type");
+    };
+    assert_eq!(shadow.kind(), TextureKind::Depth);
+    assert_eq!(shadow.dimension(), TextureDimension::Cube);
+
+    assert!(matches!(
+        shader.bindings[3].binding_type(),
+        Type::Sampler(Sampler::Comparison)
+    ));
+
+    let Type::Texture(output) = shader.bindings[4].binding_type() else {
+        panic!("Expected texture type");
+    };
+    assert_eq!(output.kind(), TextureKind::Storage);
+    assert_eq!(output.texel_format(), Some("rgba8unorm"));
+    assert_eq!(output.access_mode(), Some(AccessMode::Write));
+}
+
+#[test]
+fn test_parse_pointer_and_atomic_types() {
+    use wgsldoc::models::types::{AccessMode, AddressSpace, Type};
+
+    let shader_code = r#"
+struct Counters {
+    hits: atomic<u32>,
+}
+
+fn accumulate(particles: ptr<storage, array<Particle>, read_write>, sum: ptr<function, f32>) {}
+"#;
+
+    let result = WgslParser::parse("test", shader_code);
+    assert!(result.is_ok());
+    let shader = result.unwrap();
+
+    assert!(matches!(
+        shader.structures[0].fields()[0].field_type(),
+        Type::Atomic(_)
+    ));
+
+    let Type::Pointer(particles) = shader.functions[0].args()[0].argument_type() else {
+        panic!("Expected pointer type");
+    };
+    assert_eq!(particles.address_space(), AddressSpace::Storage);
+    assert_eq!(particles.access_mode(), Some(AccessMode::ReadWrite));
+    assert!(matches!(particles.pointee_type(), Type::Array(_)));
+
+    let Type::Pointer(sum) = shader.functions[0].args()[1].argument_type() else {
+        panic!("Expected pointer type");
+    };
+    assert_eq!(sum.address_space(), AddressSpace::Function);
+    assert!(sum.access_mode().is_none());
+}
+
 #[test]
 fn test_parse_path_type() {
     let shader_code = r#"
@@ -301,3 +800,526 @@ struct Vectors {
     assert_eq!(shader.structures.len(), 1);
     assert_eq!(shader.structures[0].fields().len(), 3);
 }
+
+#[test]
+fn test_parse_function_references() {
+    let shader_code = r#"
+fn shade(light: Light) -> f32 {
+    // shadow_map is only mentioned in a comment
+    let value = textureSample(albedo, albedo_sampler, light.uv).x;
+    return utils::saturate(value * lights.intensity) + value;
+}
+"#;
+
+    let result = WgslParser::parse("test", shader_code);
+    assert!(result.is_ok());
+    let shader = result.unwrap();
+
+    let function = &shader.functions[0];
+    assert_eq!(function.name(), "shade");
+
+    let references = function.references();
+    assert!(references.iter().any(|r| r == "albedo"));
+    assert!(references.iter().any(|r| r == "albedo_sampler"));
+    assert!(references.iter().any(|r| r == "utils::saturate"));
+    assert!(references.iter().any(|r| r == "lights"));
+    assert!(!references.iter().any(|r| r == "shadow_map"));
+    assert!(!references.iter().any(|r| r == "uv" || r == "intensity"));
+    assert_eq!(references.iter().filter(|r| *r == "value").count(), 1);
+}
+
+#[test]
+fn test_parse_path_type_generics() {
+    use wgsldoc::models::types::Type;
+
+    let shader_code = r#"
+struct Light { color: vec4<f32> }
+
+struct Scene {
+    lights: Buffer<Light, utils::Handle<u32>>,
+}
+"#;
+
+    let result = WgslParser::parse("test", shader_code);
+    assert!(result.is_ok());
+    let shader = result.unwrap();
+
+    let Type::Path(buffer) = shader.structures[1].fields()[0].field_type() else {
+        panic!("Expected path type");
+    };
+    assert_eq!(buffer.name(), "Buffer");
+    assert_eq!(buffer.generics().len(), 2);
+
+    let Type::Path(light) = &buffer.generics()[0] else {
+        panic!("Expected path type");
+    };
+    assert_eq!(light.name(), "Light");
+
+    let Type::Path(handle) = &buffer.generics()[1] else {
+        panic!("Expected path type");
+    };
+    assert_eq!(handle.module(), Some("utils"));
+    assert!(matches!(handle.generics()[0], Type::Primitive(_)));
+}
+
+#[test]
+fn test_parse_fully_qualified_path_types() {
+    use wgsldoc::models::types::Type;
+
+    let shader_code =
+        "fn fs_main(in: bevy_pbr::forward_io::VertexOutput) -> array<a::b::c::Color, 4> {}";
+
+    let shader = WgslParser::parse("test", shader_code).unwrap();
+    let function = &shader.functions[0];
+
+    let Type::Path(input) = function.args()[0].argument_type() else {
+        panic!("Expected path type");
+    };
+    assert_eq!(input.module(), Some("bevy_pbr::forward_io"));
+    assert_eq!(input.name(), "VertexOutput");
+
+    let Some(Type::Array(array)) = function.return_type() else {
+        panic!("Expected array type");
+    };
+    let Type::Path(color) = array.element_type() else {
+        panic!("Expected path type");
+    };
+    assert_eq!(color.module(), Some("a::b::c"));
+    assert_eq!(color.name(), "Color");
+}
+
+#[test]
+fn test_parse_predeclared_aliases() {
+    use wgsldoc::models::types::{Primitive, Type, VectorDimension};
+
+    let shader_code = r#"
+struct Vertex {
+    position: vec3f,
+    color: vec4h,
+    cell: vec2i,
+    index: vec2u,
+    transform: mat4x4f,
+    normal: mat3x2h,
+    not_alias: mat2x2i,
+}
+"#;
+
+    let result = WgslParser::parse("test", shader_code);
+    assert!(result.is_ok());
+    let shader = result.unwrap();
+    let fields = shader.structures[0].fields();
+
+    let expected_vectors = [
+        (VectorDimension::D3, Primitive::Float32),
+        (VectorDimension::D4, Primitive::Float16),
+        (VectorDimension::D2, Primitive::Sint32),
+        (VectorDimension::D2, Primitive::Uint32),
+    ];
+
+    for (field, (dimension, primitive)) in fields.iter().zip(expected_vectors) {
+        let Type::Vector(vector) = field.field_type() else {
+            panic!("Expected vector type for field `{}`", field.name());
+        };
+        assert_eq!(vector.dimension().to_string(), dimension.to_string());
+        assert_eq!(vector.vector_type().to_string(), primitive.to_string());
+    }
+
+    let Type::Matrix(transform) = fields[4].field_type() else {
+        panic!("Expected matrix type");
+    };
+    assert_eq!(transform.to_string(), "mat4x4&lt;f32&gt;");
+
+    let Type::Matrix(normal) = fields[5].field_type() else {
+        panic!("Expected matrix type");
+    };
+    assert_eq!(normal.to_string(), "mat3x2&lt;f16&gt;");
+
+    // Integer matrices are not predeclared
+    assert!(matches!(fields[6].field_type(), Type::Path(_)));
+}
+
+#[test]
+fn test_parse_builtin_imports() {
+    let shader_code = r#"
+/// Vertex inputs and outputs
+#import bevy_pbr::forward_io::{Vertex, VertexOutput}
+#import bevy_pbr::forward_io::{FragmentOutput}
+#import bevy_pbr::mesh_view_bindings as view_bindings
+#import bevy_render::maths
+"#;
+
+    let result = WgslParser::parse("test", shader_code);
+    assert!(result.is_ok());
+    let shader = result.unwrap();
+    assert_eq!(shader.imports.len(), 4);
+
+    let forward_io = &shader.imports[0];
+    assert_eq!(forward_io.module_path(), Some("bevy_pbr::forward_io"));
+    assert_eq!(forward_io.name(), "forward_io");
+    assert!(forward_io.docs().is_some());
+    assert_eq!(
+        forward_io
+            .items()
+            .iter()
+            .map(|item| item.name())
+            .collect::<Vec<_>>(),
+        ["Vertex", "VertexOutput"]
+    );
+
+    assert_eq!(shader.imports[1].items()[0].name(), "FragmentOutput");
+
+    assert_eq!(shader.imports[2].name(), "view_bindings");
+    assert!(shader.imports[2].items().is_empty());
+
+    assert_eq!(shader.imports[3].module_path(), Some("bevy_render::maths"));
+    assert_eq!(shader.imports[3].name(), "maths");
+}
+
+#[test]
+fn test_parse_define_import_path() {
+    let shader_code = r#"
+//! Forward rendering inputs and outputs
+#define_import_path bevy_pbr::forward_io
+
+struct Vertex {
+    position: vec3f,
+}
+"#;
+
+    let shader = WgslParser::parse("forward_io", shader_code).unwrap();
+    assert_eq!(shader.module_name, "bevy_pbr::forward_io");
+    assert_eq!(shader.import_path.as_deref(), Some("bevy_pbr::forward_io"));
+    assert!(shader.global_docs.is_some());
+    assert_eq!(shader.structures.len(), 1);
+
+    let shader = WgslParser::parse("forward_io", "struct Vertex { position: vec3f }").unwrap();
+    assert_eq!(shader.module_name, "forward_io");
+    assert!(shader.import_path.is_none());
+}
+
+#[test]
+fn test_parse_conditional_items() {
+    use wgsldoc::models::{
+        condition::{ComparisonOp, Condition},
+        import::ItemKind,
+    };
+
+    let shader_code = r#"
+#define MAX_JOINTS 256
+
+/// Skins a vertex
+#ifdef SKINNED
+fn skin(p: vec4f) -> vec4f {
+    return p * 2.0;
+}
+#else
+fn skin(p: vec4f) -> vec4f {
+    return p;
+}
+#endif
+
+#if MAX_LIGHTS >= 4
+struct Lights { count: u32 }
+#else ifdef ONE_LIGHT
+alias Lights = u32;
+#endif
+
+@vertex
+fn vs(@location(0) p: vec4f) -> @builtin(position) vec4f {
+#ifdef SKINNED
+    return skin(p);
+#else
+    return p;
+#endif
+}
+"#;
+
+    let shader = WgslParser::parse("test", shader_code).unwrap();
+    assert_eq!(shader.functions.len(), 2);
+    assert_eq!(shader.functions[0].docs(), Some("Skins a vertex"));
+
+    // Declared in both branches of the block
+    assert!(shader.conditions_of(ItemKind::Function, "skin").is_empty());
+    assert!(shader.conditions_of(ItemKind::Function, "vs").is_empty());
+    assert_eq!(
+        shader.conditions_of(ItemKind::Struct, "Lights"),
+        [vec![Condition::Comparison {
+            name: "MAX_LIGHTS".to_string(),
+            op: ComparisonOp::GreaterEqual,
+            value: "4".to_string(),
+        }]]
+    );
+
+    let alias_conditions = shader.conditions_of(ItemKind::Alias, "Lights");
+    assert_eq!(alias_conditions.len(), 1);
+    assert_eq!(alias_conditions[0].len(), 2);
+    assert_eq!(alias_conditions[0][0].to_string(), "MAX_LIGHTS < 4");
+    assert_eq!(alias_conditions[0][1].to_string(), "ONE_LIGHT");
+}
+
+#[test]
+fn test_parse_items_declared_in_several_branches() {
+    use wgsldoc::models::{condition::Condition, import::ItemKind};
+
+    let shader_code = r#"
+#ifdef X
+fn both() -> f32 { return 1.0; }
+#else
+fn both() -> f32 { return 0.0; }
+#endif
+
+#ifdef A
+#ifdef B
+fn nested() {}
+#else
+fn nested() {}
+#endif
+#endif
+
+#ifdef X
+fn some() {}
+#else ifdef Y
+fn some() {}
+#endif
+
+#ifdef X
+fn all() {}
+#else ifdef Y
+fn all() {}
+#else
+fn all() {}
+#endif
+"#;
+
+    let shader = WgslParser::parse("test", shader_code).unwrap();
+    assert_eq!(shader.functions.len(), 4);
+
+    assert!(shader.conditions_of(ItemKind::Function, "both").is_empty());
+    assert_eq!(
+        shader.conditions_of(ItemKind::Function, "nested"),
+        [vec![Condition::Defined("A".to_string())]]
+    );
+    assert_eq!(
+        shader.conditions_of(ItemKind::Function, "some"),
+        [
+            vec![Condition::Defined("X".to_string())],
+            vec![
+                Condition::NotDefined("X".to_string()),
+                Condition::Defined("Y".to_string())
+            ],
+        ]
+    );
+    assert!(shader.conditions_of(ItemKind::Function, "all").is_empty());
+}
+
+#[test]
+fn test_preprocessor_keeps_line_numbers() {
+    use wgsldoc::parser::preprocessor::Preprocessor;
+
+    let shader_code = "#ifdef A\nfn a() {}\n#ifndef B\nfn b() {}\n#endif\n#endif\nfn c() {}\n";
+    let preprocessed = Preprocessor::new().process(shader_code).unwrap();
+
+    assert_eq!(
+        preprocessed.source(),
+        "\nfn a() {}\n\nfn b() {}\n\n\nfn c() {}\n"
+    );
+    assert_eq!(preprocessed.conditions_at(2).len(), 1);
+    assert_eq!(preprocessed.conditions_at(4).len(), 2);
+    assert_eq!(preprocessed.conditions_at(4)[1].to_string(), "!B");
+    assert!(preprocessed.conditions_at(7).is_empty());
+}
+
+#[test]
+fn test_preprocessor_substitutes_shader_defs() {
+    use wgsldoc::models::condition::{ShaderDefValue, ShaderDefs};
+    use wgsldoc::parser::preprocessor::Preprocessor;
+
+    let shader_code = "#define MAX_JOINTS 256\n\
+                       const MAX_LIGHTS: u32 = #{MAX_LIGHTS};\n\
+                       const LIGHTS_AND_JOINTS = vec2(#{MAX_LIGHTS}, #{MAX_JOINTS});\n\
+                       const SHADOWS = #{SHADOWS};\n\
+                       #ifdef SKINNED\n\
+                       const SKIN = #{UNDEFINED};\n\
+                       #endif\n";
+
+    let shader_defs = ShaderDefs::from([
+        ("MAX_LIGHTS".to_string(), ShaderDefValue::UInt(16)),
+        ("SHADOWS".to_string(), ShaderDefValue::Bool(false)),
+    ]);
+    let preprocessed = Preprocessor::with_shader_defs(shader_defs)
+        .process(shader_code)
+        .unwrap();
+
+    // Disabled code isn't substituted
+    assert_eq!(
+        preprocessed.source(),
+        "\nconst MAX_LIGHTS: u32 = 16u;\n\
+         const LIGHTS_AND_JOINTS = vec2(16u, 256);\n\
+         const SHADOWS = false;\n\n\n\n"
+    );
+
+    // Without shader defs, the code is kept as is
+    let preprocessed = Preprocessor::new().process(shader_code).unwrap();
+    assert!(preprocessed.source().contains("#{MAX_LIGHTS}"));
+}
+
+#[test]
+fn test_parse_with_shader_defs() {
+    use wgsldoc::models::condition::{ShaderDefValue, ShaderDefs};
+    use wgsldoc::parser::preprocessor::Preprocessor;
+
+    let shader_code = r#"
+#define LIGHTS_ENABLED
+
+#ifdef SKINNED
+fn skin(p: vec4f) -> vec4f {
+    return p * 2.0;
+}
+#endif
+
+#if MAX_LIGHTS >= 4
+struct Lights { count: u32 }
+#else ifdef LIGHTS_ENABLED
+alias Lights = u32;
+#endif
+"#;
+
+    let shader_defs = ShaderDefs::from([("MAX_LIGHTS".to_string(), ShaderDefValue::Int(2))]);
+    let preprocessor = Preprocessor::with_shader_defs(shader_defs);
+    let shader = WgslParser::parse_with_preprocessor("test", shader_code, &preprocessor).unwrap();
+
+    assert!(shader.functions.is_empty());
+    assert!(shader.structures.is_empty());
+    assert_eq!(shader.aliases.len(), 1);
+    assert!(shader.conditions.is_empty());
+
+    // Comparisons with shader defs that aren't defined can't be evaluated
+    let preprocessor = Preprocessor::with_shader_defs(ShaderDefs::new());
+    assert!(WgslParser::parse_with_preprocessor("test", shader_code, &preprocessor).is_err());
+}
+
+#[test]
+fn test_parse_function_modifiers() {
+    use wgsldoc::models::function::FunctionModifier;
+
+    let shader_code = r#"
+virtual fn point_light(light_id: u32) -> vec3f {
+    return vec3f(0.0);
+}
+
+override fn bevy_pbr::lighting::directional_light(light_id: u32) -> vec3f {
+    return vec3f(1.0);
+}
+
+override scale: f32 = 1.0;
+"#;
+
+    let shader = WgslParser::parse("test", shader_code).unwrap();
+    assert_eq!(shader.functions.len(), 2);
+    assert_eq!(shader.overrides.len(), 1);
+
+    assert!(shader.functions[0].is_virtual());
+    assert_eq!(shader.functions[1].name(), "directional_light");
+    assert_eq!(
+        shader.functions[1].modifier(),
+        Some(&FunctionModifier::Override {
+            module_path: "bevy_pbr::lighting".to_string(),
+            module: None,
+        })
+    );
+}
+
+#[test]
+fn test_parse_wesl_imports() {
+    let shader_code = r#"
+/// Lighting functions
+import package::lighting::{Light, pbr, shadows::sample};
+import super::util;
+import package::{util::helper, lighting};
+"#;
+
+    let shader = WgslParser::parse("test", shader_code).unwrap();
+    let imports = shader
+        .imports
+        .iter()
+        .map(|import| {
+            let items = import
+                .items()
+                .iter()
+                .map(|item| item.name())
+                .collect::<Vec<_>>();
+
+            (import.module_path().unwrap(), items)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        imports,
+        [
+            ("package::lighting", vec!["Light", "pbr"]),
+            ("package::lighting::shadows", vec!["sample"]),
+            ("super::util", vec![]),
+            ("package::util", vec!["helper"]),
+            ("package::lighting", vec![]),
+        ]
+    );
+    assert_eq!(shader.imports[0].docs(), Some("<p>Lighting functions</p>"));
+    assert_eq!(shader.imports[2].name(), "util");
+}
+
+#[test]
+fn test_parse_recovering_skips_invalid_items() {
+    use wgsldoc::parser::preprocessor::Preprocessor;
+
+    let shader_code = r#"//! Module with invalid items
+
+fn first() -> f32 {
+    return 1.0;
+}
+
+/// Unknown attribute
+@whatever(1) fn invalid(x: f32) -> f32 {
+    if (x > 0.0) { return x; }
+    return 0.0;
+}
+
+this is not an item;
+
+struct Point {
+    x: f32,
+}
+
+fn unclosed( {
+"#;
+
+    assert!(WgslParser::parse("test", shader_code).is_err());
+
+    let shader = WgslParser::parse_recovering("test", shader_code, &Preprocessor::new()).unwrap();
+
+    assert_eq!(
+        shader.global_docs.as_deref(),
+        Some("Module with invalid items")
+    );
+    assert_eq!(shader.functions.len(), 1);
+    assert_eq!(shader.functions[0].name(), "first");
+    assert_eq!(shader.structures.len(), 1);
+    assert_eq!(shader.structures[0].name(), "Point");
+
+    let skipped = &shader.skipped_items;
+    assert_eq!(skipped.len(), 3);
+    assert_eq!(skipped[0].line(), 7);
+    assert!(skipped[0]
+        .source()
+        .starts_with("/// Unknown attribute\n@whatever(1) fn invalid"));
+    assert!(skipped[0].source().ends_with('}'));
+    assert_eq!(skipped[1].line(), 13);
+    assert_eq!(skipped[1].source(), "this is not an item;");
+    assert_eq!(skipped[2].line(), 19);
+    assert!(skipped[2].message().contains("line 19"));
+
+    // Valid shaders have nothing to skip
+    let shader =
+        WgslParser::parse_recovering("test", "fn valid() {}", &Preprocessor::new()).unwrap();
+    assert_eq!(shader.functions.len(), 1);
+    assert!(shader.skipped_items.is_empty());
+}
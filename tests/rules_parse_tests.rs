@@ -47,6 +47,26 @@ const PI: f32 = 3.14159;
     assert!(result.is_ok(), "Complex shader should parse");
 }
 
+#[test]
+fn test_rule_shader_with_directives() {
+    let input = r#"
+//! Global docs
+enable f16, clip_distances;
+requires readonly_and_readwrite_storage_textures;
+diagnostic(off, derivative_uniformity);
+fn test() {}
+"#;
+    let result = WgslGrammarTester::parse(Rule::SHADER, input);
+    assert!(result.is_ok(), "Shader with directives should parse");
+}
+
+#[test]
+fn test_rule_diagnostic_directive_namespaced() {
+    let input = "diagnostic(warning, chromium.unreachable_code);";
+    let result = WgslGrammarTester::parse(Rule::DIAGNOSTIC_DIRECTIVE, input);
+    assert!(result.is_ok(), "Namespaced diagnostic rule should parse");
+}
+
 #[test]
 fn test_rule_number() {
     let input = "42";
@@ -131,6 +151,30 @@ fn test_rule_storage_class_workgroup() {
     assert!(result.is_ok(), "workgroup storage class should parse");
 }
 
+#[test]
+fn test_rule_storage_class_function_invalid() {
+    let input = "function";
+    let result = WgslGrammarTester::parse(Rule::STORAGE_CLASS, input);
+    assert!(
+        result.is_err(),
+        "function storage class is only valid for pointers"
+    );
+
+    let input = "var<function> x: f32;";
+    let result = WgslGrammarTester::parse(Rule::GLOBAL_VAR, input);
+    assert!(
+        result.is_err(),
+        "module-scope function variable should not parse"
+    );
+}
+
+#[test]
+fn test_rule_address_space_function() {
+    let input = "function";
+    let result = WgslGrammarTester::parse(Rule::ADDRESS_SPACE, input);
+    assert!(result.is_ok(), "function address space should parse");
+}
+
 #[test]
 fn test_rule_var_template() {
     let input = "<uniform>";
@@ -145,6 +189,16 @@ fn test_rule_var_template_storage() {
     assert!(result.is_ok(), "<storage> template should parse");
 }
 
+#[test]
+fn test_rule_var_template_access_mode() {
+    let input = "<storage, read_write>";
+    let result = WgslGrammarTester::parse(Rule::VAR_TEMPLATE, input);
+    assert!(
+        result.is_ok(),
+        "<storage, read_write> template should parse"
+    );
+}
+
 #[test]
 fn test_rule_resource_binding_simple() {
     let input = "@group(0) @binding(0) var<uniform> myBuffer: f32;";
@@ -173,6 +227,54 @@ fn test_rule_resource_binding_no_semicolon() {
     assert!(result.is_ok(), "Resource binding without semicolon should parse");
 }
 
+#[test]
+fn test_rule_global_var_workgroup() {
+    let input = "/// Shared partial sums\nvar<workgroup> sums: Sums;";
+    let result = WgslGrammarTester::parse(Rule::GLOBAL_VAR, input);
+    assert!(result.is_ok(), "Workgroup variable should parse");
+}
+
+#[test]
+fn test_rule_global_var_private_with_initializer() {
+    let input = "var<private> seed: u32 = 0u;";
+    let result = WgslGrammarTester::parse(Rule::GLOBAL_VAR, input);
+    assert!(result.is_ok(), "Initialized private variable should parse");
+}
+
+#[test]
+fn test_rule_global_var_uniform_without_binding() {
+    let input = "var<uniform> camera: Camera;";
+    let result = WgslGrammarTester::parse(Rule::GLOBAL_VAR, input);
+    assert!(
+        result.is_err(),
+        "Uniform variable without binding should not parse"
+    );
+}
+
+#[test]
+fn test_rule_global_var_storage_without_binding() {
+    let input = "var<storage, read_write> particles: array<Particle>;";
+    let result = WgslGrammarTester::parse(Rule::GLOBAL_VAR, input);
+    assert!(
+        result.is_err(),
+        "Storage variable without binding should not parse"
+    );
+
+    let result = WgslGrammarTester::parse(Rule::SHADER, input);
+    assert!(
+        result.is_err(),
+        "Shader with storage variable without binding should not parse"
+    );
+}
+
+#[test]
+fn test_rule_recovering_shader_skips_uniform_without_binding() {
+    let input = "var<uniform> camera: Camera;\nvar<private> seed: u32;";
+    let result = WgslGrammarTester::parse(Rule::RECOVERING_SHADER, input).unwrap();
+    let rules = result.map(|pair| pair.as_rule()).collect::<Vec<_>>();
+    assert_eq!(rules, [Rule::SKIPPED_ITEM, Rule::GLOBAL_VAR, Rule::EOI]);
+}
+
 #[test]
 fn test_rule_const_simple() {
     let input = "const PI: f32 = 3.14159;";
@@ -215,6 +317,25 @@ fn test_rule_const_value_expression() {
     assert!(result.is_ok(), "Expression constant value should parse");
 }
 
+#[test]
+fn test_rule_override_with_id() {
+    let input = "@id(0) override BLOCK_SIZE: u32 = 64;";
+    let result = WgslGrammarTester::parse(Rule::OVERRIDE, input);
+    assert!(result.is_ok(), "Override with id should parse");
+
+    for input in ["@id(0u) override A: u32;", "@id(0x1F) override A: u32;"] {
+        let result = WgslGrammarTester::parse(Rule::OVERRIDE, input);
+        assert!(result.is_ok(), "Override `{input}` should parse");
+    }
+}
+
+#[test]
+fn test_rule_override_without_default() {
+    let input = "/// Must be provided by the pipeline\noverride GAIN: f32;";
+    let result = WgslGrammarTester::parse(Rule::OVERRIDE, input);
+    assert!(result.is_ok(), "Override without default value should parse");
+}
+
 #[test]
 fn test_rule_import_path_simple() {
     let input = "utils.wgsl";
@@ -313,6 +434,20 @@ fn test_rule_builtin_import_no_semicolon() {
     assert!(result.is_ok(), "Builtin import without semicolon should parse");
 }
 
+#[test]
+fn test_rule_define_import_path() {
+    let input = "#define_import_path bevy_pbr::forward_io";
+    let result = WgslGrammarTester::parse(Rule::DEFINE_IMPORT_PATH, input);
+    assert!(result.is_ok(), "Import path definition should parse");
+}
+
+#[test]
+fn test_rule_builtin_import_with_alias() {
+    let input = "#import bevy_pbr::mesh_view_bindings as view_bindings";
+    let result = WgslGrammarTester::parse(Rule::BUILTIN_IMPORT, input);
+    assert!(result.is_ok(), "Builtin import with alias should parse");
+}
+
 #[test]
 fn test_rule_builtin_import_content_simple() {
     let input = "utils";
@@ -383,6 +518,30 @@ fn test_rule_structure_with_docs() {
     assert!(result.is_ok(), "Structure with docs should parse");
 }
 
+#[test]
+fn test_rule_field_with_member_attributes() {
+    let input = "@location(0) @interpolate(flat) id: u32";
+    let result = WgslGrammarTester::parse(Rule::FIELD, input);
+    assert!(
+        result.is_ok(),
+        "Field with multiple attributes should parse"
+    );
+}
+
+#[test]
+fn test_rule_field_with_layout_attributes() {
+    let input = "@align(16) @size(32) data: vec3<f32>";
+    let result = WgslGrammarTester::parse(Rule::FIELD, input);
+    assert!(result.is_ok(), "Field with layout attributes should parse");
+}
+
+#[test]
+fn test_rule_field_invariant_builtin() {
+    let input = "@builtin(position) @invariant position: vec4<f32>";
+    let result = WgslGrammarTester::parse(Rule::FIELD, input);
+    assert!(result.is_ok(), "Invariant builtin field should parse");
+}
+
 #[test]
 fn test_rule_fields_single() {
     let input = "x: f32";
@@ -439,6 +598,37 @@ fn test_rule_field_vector_type() {
     assert!(result.is_ok(), "Field with vector type should parse");
 }
 
+#[test]
+fn test_rule_alias_simple() {
+    let input = "alias Color = vec4<f32>;";
+    let result = WgslGrammarTester::parse(Rule::ALIAS, input);
+    assert!(result.is_ok(), "Simple alias should parse");
+}
+
+#[test]
+fn test_rule_alias_with_docs() {
+    let input = "/// Handle to a light\nalias LightHandle = Lights::Light;";
+    let result = WgslGrammarTester::parse(Rule::ALIAS, input);
+    assert!(result.is_ok(), "Alias with docs should parse");
+}
+
+#[test]
+fn test_rule_workgroup_size_with_expressions() {
+    let input = "@workgroup_size(BLOCK_SIZE, (BLOCK_SIZE / 2), 1)";
+    let result = WgslGrammarTester::parse(Rule::WORKGROUP_SIZE, input);
+    assert!(
+        result.is_ok(),
+        "Workgroup size with expressions should parse"
+    );
+}
+
+#[test]
+fn test_rule_entry() {
+    let input = "@fragment";
+    let result = WgslGrammarTester::parse(Rule::ENTRY, input);
+    assert!(result.is_ok(), "Fragment entry attribute should parse");
+}
+
 #[test]
 fn test_rule_function_simple() {
     let input = "fn test() {}";
@@ -607,34 +797,124 @@ fn test_rule_type_path() {
     assert!(result.is_ok(), "Path type should parse");
 }
 
+#[test]
+fn test_rule_type_array_fixed() {
+    let input = "array<f32, 16>";
+    let result = WgslGrammarTester::parse(Rule::TYPE, input);
+    assert!(result.is_ok(), "Fixed-size array type should parse");
+}
+
+#[test]
+fn test_rule_type_array_runtime_sized() {
+    let input = "array<Light>";
+    let result = WgslGrammarTester::parse(Rule::TYPE, input);
+    assert!(result.is_ok(), "Runtime-sized array type should parse");
+}
+
+#[test]
+fn test_rule_array_nested_with_const_size() {
+    let input = "array<array<vec4<f32>, 4>, MAX_LIGHTS>";
+    let result = WgslGrammarTester::parse(Rule::ARRAY, input);
+    assert!(result.is_ok(), "Nested array with const size should parse");
+}
+
+#[test]
+fn test_rule_matrix() {
+    let input = "mat4x3<f32>";
+    let result = WgslGrammarTester::parse(Rule::MATRIX, input);
+    assert!(result.is_ok(), "Matrix type should parse");
+}
+
+#[test]
+fn test_rule_matrix_invalid_dimension() {
+    let input = "mat5x4<f32>";
+    let result = WgslGrammarTester::parse(Rule::MATRIX, input);
+    assert!(result.is_err(), "Matrix with 5 columns should not parse");
+}
+
+#[test]
+fn test_rule_texture_sampled() {
+    let input = "texture_2d_array<f32>";
+    let result = WgslGrammarTester::parse(Rule::TEXTURE, input);
+    assert!(result.is_ok(), "Sampled texture should parse");
+}
+
+#[test]
+fn test_rule_texture_storage() {
+    let input = "texture_storage_2d<rgba8unorm, write>";
+    let result = WgslGrammarTester::parse(Rule::TEXTURE, input);
+    assert!(result.is_ok(), "Storage texture should parse");
+}
+
+#[test]
+fn test_rule_texture_depth() {
+    let input = "texture_depth_cube";
+    let result = WgslGrammarTester::parse(Rule::TEXTURE, input);
+    assert!(result.is_ok(), "Depth texture should parse");
+}
+
+#[test]
+fn test_rule_sampler() {
+    assert!(WgslGrammarTester::parse(Rule::SAMPLER, "sampler").is_ok());
+    assert!(WgslGrammarTester::parse(Rule::SAMPLER, "sampler_comparison").is_ok());
+    assert!(
+        WgslGrammarTester::parse(Rule::SAMPLER, "samplers").is_err(),
+        "Identifier starting with `sampler` should not parse as a sampler"
+    );
+}
+
 #[test]
 fn test_rule_function_type_primitive() {
     let input = "f32";
-    let result = WgslGrammarTester::parse(Rule::FUNCTION_TYPE, input);
+    let result = WgslGrammarTester::parse(Rule::TYPE, input);
     assert!(result.is_ok(), "Primitive function type should parse");
 }
 
 #[test]
 fn test_rule_function_type_pointer() {
     let input = "ptr<function, f32>";
-    let result = WgslGrammarTester::parse(Rule::FUNCTION_TYPE, input);
+    let result = WgslGrammarTester::parse(Rule::TYPE, input);
     assert!(result.is_ok(), "Function pointer type should parse");
 }
 
 #[test]
 fn test_rule_function_pointer_simple() {
     let input = "ptr<function, f32>";
-    let result = WgslGrammarTester::parse(Rule::FUNCTION_POINTER, input);
+    let result = WgslGrammarTester::parse(Rule::POINTER, input);
     assert!(result.is_ok(), "Simple function pointer should parse");
 }
 
 #[test]
 fn test_rule_function_pointer_vector() {
     let input = "ptr<function, vec3<f32>>";
-    let result = WgslGrammarTester::parse(Rule::FUNCTION_POINTER, input);
+    let result = WgslGrammarTester::parse(Rule::POINTER, input);
     assert!(result.is_ok(), "Function pointer to vector should parse");
 }
 
+#[test]
+fn test_rule_pointer_storage_with_access_mode() {
+    let input = "ptr<storage, array<Particle>, read_write>";
+    let result = WgslGrammarTester::parse(Rule::POINTER, input);
+    assert!(
+        result.is_ok(),
+        "Storage pointer with access mode should parse"
+    );
+}
+
+#[test]
+fn test_rule_pointer_workgroup() {
+    let input = "ptr<workgroup, f32>";
+    let result = WgslGrammarTester::parse(Rule::POINTER, input);
+    assert!(result.is_ok(), "Workgroup pointer should parse");
+}
+
+#[test]
+fn test_rule_atomic() {
+    let input = "atomic<u32>";
+    let result = WgslGrammarTester::parse(Rule::ATOMIC, input);
+    assert!(result.is_ok(), "Atomic type should parse");
+}
+
 #[test]
 fn test_rule_generic_args_single() {
     let input = "<f32>";
@@ -670,6 +950,17 @@ fn test_rule_path_type_with_module() {
     assert!(result.is_ok(), "Path type with module should parse");
 }
 
+#[test]
+fn test_rule_path_type_with_module_path() {
+    let input = "bevy_pbr::forward_io::VertexOutput";
+    let result = WgslGrammarTester::parse(Rule::PATH_TYPE, input).unwrap();
+    let module = result.flatten().find(|pair| pair.as_rule() == Rule::MODULE);
+    assert_eq!(
+        module.map(|pair| pair.as_str()),
+        Some("bevy_pbr::forward_io")
+    );
+}
+
 #[test]
 fn test_rule_path_type_with_generics() {
     let input = "Array<f32>";
@@ -705,6 +996,13 @@ fn test_rule_primitive_bool() {
     assert!(result.is_ok(), "bool primitive should parse");
 }
 
+#[test]
+fn test_rule_primitive_f16() {
+    let input = "f16";
+    let result = WgslGrammarTester::parse(Rule::PRIMITIVE, input);
+    assert!(result.is_ok(), "f16 primitive should parse");
+}
+
 #[test]
 fn test_rule_primitive_f32() {
     let input = "f32";
@@ -957,6 +1255,27 @@ fn test_rule_docs_with_markdown() {
     assert!(result.is_ok(), "Docs with markdown should parse");
 }
 
+#[test]
+fn test_rule_docs_block() {
+    let input = "/**\n * Summary\n *\n * Details\n */";
+    let result = WgslGrammarTester::parse(Rule::DOCS, input);
+    assert!(result.is_ok(), "Block docs should parse");
+}
+
+#[test]
+fn test_rule_docs_block_single_line() {
+    let input = "/** Summary */";
+    let result = WgslGrammarTester::parse(Rule::DOCS, input);
+    assert!(result.is_ok(), "Single line block docs should parse");
+}
+
+#[test]
+fn test_rule_global_docs_block() {
+    let input = "/*! Module docs */";
+    let result = WgslGrammarTester::parse(Rule::GLOBAL_DOCS, input);
+    assert!(result.is_ok(), "Block global docs should parse");
+}
+
 #[test]
 fn test_rule_docs_content_simple() {
     let input = "Simple documentation text";
@@ -1117,3 +1436,86 @@ struct Container {
     let result = WgslGrammarTester::parse(Rule::STRUCTURE, input);
     assert!(result.is_ok(), "Nested generic types should parse");
 }
+
+#[test]
+fn test_rule_function_with_references() {
+    let input = "fn main() { let color = textureSample(albedo, albedo_sampler, in.uv).rgb; utils::tonemap(color); }";
+    let result = WgslGrammarTester::parse(Rule::FUNCTION, input);
+    assert!(result.is_ok(), "Function body with references should parse");
+
+    let references = result
+        .unwrap()
+        .flatten()
+        .filter(|pair| pair.as_rule() == Rule::REFERENCE)
+        .map(|pair| pair.as_str())
+        .collect::<Vec<_>>();
+    assert!(references.contains(&"albedo_sampler"));
+    assert!(references.contains(&"utils::tonemap"));
+    assert!(!references.contains(&"uv"));
+}
+
+#[test]
+fn test_rule_function_with_modifiers() {
+    let input = "virtual fn point_light(light_id: u32) -> vec3f { return vec3f(0.0); }";
+    let result = WgslGrammarTester::parse(Rule::FUNCTION, input);
+    assert!(result.is_ok(), "Virtual function should parse");
+
+    let input = "override fn bevy_pbr::lighting::point_light(light_id: u32) -> vec3f { return vec3f(1.0); }";
+    let result = WgslGrammarTester::parse(Rule::FUNCTION, input);
+    assert!(result.is_ok(), "Override function should parse");
+
+    let overridden_module = result
+        .unwrap()
+        .flatten()
+        .find(|pair| pair.as_rule() == Rule::OVERRIDDEN_MODULE)
+        .map(|pair| pair.as_str());
+    assert_eq!(overridden_module, Some("bevy_pbr::lighting"));
+}
+
+#[test]
+fn test_rule_wesl_import() {
+    let inputs = [
+        "import package::lighting::pbr;",
+        "import super::util;",
+        "import package::lighting::{pbr, shadows::sample,};",
+        "import {bevy_pbr::lighting, util::helper};",
+    ];
+
+    for input in inputs {
+        let result = WgslGrammarTester::parse(Rule::WESL_IMPORT, input);
+        assert!(result.is_ok(), "WESL import `{input}` should parse");
+    }
+
+    let result = WgslGrammarTester::parse(Rule::WESL_IMPORT, "import package::lighting");
+    assert!(result.is_err(), "WESL import requires a semicolon");
+}
+
+#[test]
+fn test_rule_skipped_item() {
+    let inputs = [
+        ("garbage;\nfn next() {}", "garbage;"),
+        (
+            "@unknown fn f() { if (a) { b(); } };\nfn next() {}",
+            "@unknown fn f() { if (a) { b(); } };",
+        ),
+        (
+            "fn unclosed( {\nfn next() {}",
+            "fn unclosed( {\nfn next() {}",
+        ),
+        ("trailing garbage", "trailing garbage"),
+    ];
+
+    for (input, skipped) in inputs {
+        let result = WgslGrammarTester::parse(Rule::SKIPPED_ITEM, input);
+        assert!(result.is_ok(), "Item `{input}` should be skipped");
+        assert_eq!(result.unwrap().as_str(), skipped);
+    }
+
+    let input = "fn valid() {}\ngarbage;\nstruct S { x: f32 }";
+    let result = WgslGrammarTester::parse(Rule::RECOVERING_SHADER, input);
+    assert!(
+        result.is_ok(),
+        "Recovering shader should skip invalid items"
+    );
+    assert!(WgslGrammarTester::parse(Rule::SHADER, input).is_err());
+}
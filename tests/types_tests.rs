@@ -1,9 +1,13 @@
 use std::str::FromStr;
-use wgsldoc::models::types::{ImportModule, PathType, Primitive, Type, Vector, VectorDimension};
+use wgsldoc::models::types::{
+    AccessMode, AddressSpace, Array, ImportModule, Matrix, PathType, Pointer, Primitive, Sampler,
+    Texture, TextureDimension, TextureKind, Type, Vector, VectorDimension,
+};
 
 #[test]
 fn test_primitive_display() {
     assert_eq!(format!("{}", Primitive::Bool), "bool");
+    assert_eq!(format!("{}", Primitive::Float16), "f16");
     assert_eq!(format!("{}", Primitive::Float32), "f32");
     assert_eq!(format!("{}", Primitive::Float64), "f64");
     assert_eq!(format!("{}", Primitive::Uint8), "u8");
@@ -19,6 +23,7 @@ fn test_primitive_display() {
 #[test]
 fn test_primitive_from_str() {
     assert!(matches!(Primitive::from_str("bool"), Ok(Primitive::Bool)));
+    assert!(matches!(Primitive::from_str("f16"), Ok(Primitive::Float16)));
     assert!(matches!(Primitive::from_str("f32"), Ok(Primitive::Float32)));
     assert!(matches!(Primitive::from_str("f64"), Ok(Primitive::Float64)));
     assert!(matches!(Primitive::from_str("u32"), Ok(Primitive::Uint32)));
@@ -26,6 +31,50 @@ fn test_primitive_from_str() {
     assert!(Primitive::from_str("invalid").is_err());
 }
 
+#[test]
+fn test_primitive_core_and_extensions() {
+    assert!(Primitive::Bool.is_core());
+    assert!(Primitive::Float32.is_core());
+    assert!(Primitive::Uint32.is_core());
+    assert!(!Primitive::Float16.is_core());
+    assert!(!Primitive::Float64.is_core());
+    assert_eq!(Primitive::Float16.extension(), Some("f16"));
+    assert_eq!(Primitive::Float32.extension(), None);
+    assert_eq!(Primitive::Sint8.extension(), None);
+    assert!(Primitive::Float16.is_standard());
+    assert!(!Primitive::Uint64.is_standard());
+}
+
+#[test]
+fn test_type_is_core() {
+    let core = Type::Vector(Vector::new(VectorDimension::D3, Primitive::Float32));
+    assert!(core.is_core());
+    assert!(core.is_standard());
+    assert!(core.rendered_type(&[]).extension.is_none());
+
+    let half = Type::Array(Array::new(
+        Type::Vector(Vector::new(VectorDimension::D4, Primitive::Float16)),
+        None,
+    ));
+    assert!(!half.is_core());
+    assert!(half.is_standard());
+    assert_eq!(half.extension(), Some("f16"));
+
+    let rendered = half.rendered_type(&[]);
+    assert!(rendered.extension.is_none());
+    let element = rendered.element.unwrap();
+    assert_eq!(element.extension.as_deref(), Some("f16"));
+    assert!(!element.is_non_standard);
+
+    let double = Type::Vector(Vector::new(VectorDimension::D2, Primitive::Float64));
+    assert!(!double.is_standard());
+    assert_eq!(double.extension(), None);
+
+    let rendered = double.rendered_type(&[]);
+    assert!(rendered.is_non_standard);
+    assert!(rendered.extension.is_none());
+}
+
 #[test]
 fn test_vector_display() {
     let vec2 = Vector::new(VectorDimension::D2, Primitive::Float32);
@@ -57,7 +106,7 @@ fn test_vector_dimension_from_str() {
 
 #[test]
 fn test_path_type_new() {
-    let path = PathType::new(None, "Camera".to_string());
+    let path = PathType::new(None, "Camera".to_string(), vec![]);
     assert_eq!(path.name(), "Camera");
     assert!(path.module().is_none());
     assert_eq!(*path.import_module(), ImportModule::Undefined);
@@ -65,7 +114,7 @@ fn test_path_type_new() {
 
 #[test]
 fn test_path_type_with_module() {
-    let path = PathType::new(Some("Utils".to_string()), "Camera".to_string());
+    let path = PathType::new(Some("Utils".to_string()), "Camera".to_string(), vec![]);
     assert_eq!(path.name(), "Camera");
     assert_eq!(path.module(), Some("Utils"));
     assert_eq!(*path.import_module(), ImportModule::Undefined);
@@ -74,34 +123,157 @@ fn test_path_type_with_module() {
 #[test]
 fn test_rendered_type_primitive() {
     let ty = Type::Primitive(Primitive::Float32);
-    let rendered = ty.rendered_type(&[], false);
+    let rendered = ty.rendered_type(&[]);
     assert_eq!(rendered.name, "f32");
-    assert!(!rendered.is_function_pointer);
+    assert!(rendered.address_space.is_none());
     assert!(rendered.import.is_none());
 }
 
 #[test]
 fn test_rendered_type_vector() {
     let ty = Type::Vector(Vector::new(VectorDimension::D3, Primitive::Float32));
-    let rendered = ty.rendered_type(&[], false);
+    let rendered = ty.rendered_type(&[]);
     assert_eq!(rendered.name, "vec3&lt;f32&gt;");
-    assert!(!rendered.is_function_pointer);
+    assert!(rendered.address_space.is_none());
 }
 
 #[test]
 fn test_rendered_type_path() {
-    let ty = Type::Path(PathType::new(None, "Camera".to_string()));
-    let rendered = ty.rendered_type(&[], false);
+    let ty = Type::Path(PathType::new(None, "Camera".to_string(), vec![]));
+    let rendered = ty.rendered_type(&[]);
     assert_eq!(rendered.name, "Camera");
     assert!(rendered.module.is_none());
     assert!(!rendered.is_this);
 }
 
+#[test]
+fn test_rendered_type_path_with_generics() {
+    let ty = Type::Path(PathType::new(
+        None,
+        "Buffer".to_string(),
+        vec![
+            Type::Primitive(Primitive::Float32),
+            Type::Path(PathType::new(
+                Some("utils".to_string()),
+                "Light".to_string(),
+                vec![],
+            )),
+        ],
+    ));
+    let rendered = ty.rendered_type(&[]);
+    assert_eq!(rendered.name, "Buffer");
+    assert_eq!(rendered.generics.len(), 2);
+    assert_eq!(rendered.generics[0].name, "f32");
+    assert_eq!(rendered.generics[1].module.as_deref(), Some("utils"));
+    assert_eq!(rendered.generics[1].name, "Light");
+}
+
+#[test]
+fn test_rendered_type_array() {
+    let ty = Type::Array(Array::new(
+        Type::Path(PathType::new(None, "Light".to_string(), vec![])),
+        Some("MAX_LIGHTS".to_string()),
+    ));
+    let rendered = ty.rendered_type(&[]);
+    assert_eq!(rendered.name, "array");
+    assert_eq!(rendered.size.as_deref(), Some("MAX_LIGHTS"));
+    assert_eq!(rendered.element.unwrap().name, "Light");
+}
+
+#[test]
+fn test_array_runtime_sized() {
+    let array = Array::new(Type::Primitive(Primitive::Float32), None);
+    assert!(array.is_runtime_sized());
+    assert!(array.size().is_none());
+}
+
+#[test]
+fn test_matrix_display() {
+    let matrix = Matrix::new(VectorDimension::D4, VectorDimension::D3, Primitive::Float32);
+    assert_eq!(format!("{}", matrix), "mat4x3&lt;f32&gt;");
+
+    let rendered = Type::Matrix(matrix).rendered_type(&[]);
+    assert_eq!(rendered.name, "mat4x3&lt;f32&gt;");
+}
+
+#[test]
+fn test_texture_display() {
+    let sampled = Texture::sampled(
+        TextureKind::Sampled,
+        TextureDimension::D2Array,
+        Primitive::Float32,
+    );
+    assert_eq!(format!("{}", sampled), "texture_2d_array&lt;f32&gt;");
+
+    let storage = Texture::storage(
+        TextureDimension::D3,
+        "r32float".to_string(),
+        AccessMode::ReadWrite,
+    );
+    assert_eq!(
+        format!("{}", storage),
+        "texture_storage_3d&lt;r32float, read_write&gt;"
+    );
+
+    let depth = Texture::new(TextureKind::DepthMultisampled, TextureDimension::D2);
+    assert_eq!(format!("{}", depth), "texture_depth_multisampled_2d");
+}
+
+#[test]
+fn test_types_to_wgsl() {
+    let vector = Vector::new(VectorDimension::D3, Primitive::Float32);
+    assert_eq!(vector.to_wgsl(), "vec3<f32>");
+
+    let matrix = Matrix::new(VectorDimension::D4, VectorDimension::D3, Primitive::Float16);
+    assert_eq!(matrix.to_wgsl(), "mat4x3<f16>");
+
+    let storage = Texture::storage(
+        TextureDimension::D2,
+        "rgba8unorm".to_string(),
+        AccessMode::Write,
+    );
+    assert_eq!(storage.to_wgsl(), "texture_storage_2d<rgba8unorm, write>");
+
+    let external = Texture::new(TextureKind::External, TextureDimension::D2);
+    assert_eq!(external.to_wgsl(), "texture_external");
+}
+
+#[test]
+fn test_rendered_type_sampler() {
+    let rendered = Type::Sampler(Sampler::Comparison).rendered_type(&[]);
+    assert_eq!(rendered.name, "sampler_comparison");
+}
+
 #[test]
 fn test_rendered_type_function_pointer() {
-    let ty = Type::Primitive(Primitive::Float32);
-    let rendered = ty.rendered_type(&[], true);
-    assert!(rendered.is_function_pointer);
+    let ty = Type::Pointer(Pointer::new(
+        AddressSpace::Function,
+        Type::Primitive(Primitive::Float32),
+        None,
+    ));
+    let rendered = ty.rendered_type(&[]);
+    assert_eq!(rendered.address_space, Some(AddressSpace::Function));
+    assert!(rendered.access_mode.is_none());
+    assert_eq!(rendered.element.unwrap().name, "f32");
+}
+
+#[test]
+fn test_rendered_type_storage_pointer() {
+    let ty = Type::Pointer(Pointer::new(
+        AddressSpace::Storage,
+        Type::Path(PathType::new(None, "Particles".to_string(), vec![])),
+        Some(AccessMode::ReadWrite),
+    ));
+    let rendered = ty.rendered_type(&[]);
+    assert_eq!(rendered.address_space, Some(AddressSpace::Storage));
+    assert_eq!(rendered.access_mode, Some(AccessMode::ReadWrite));
+}
+
+#[test]
+fn test_rendered_type_atomic() {
+    let rendered = Type::Atomic(Primitive::Uint32).rendered_type(&[]);
+    assert_eq!(rendered.name, "atomic");
+    assert_eq!(rendered.element.unwrap().name, "u32");
 }
 
 #[test]